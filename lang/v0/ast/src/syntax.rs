@@ -20,6 +20,7 @@ pub enum ASTParsec {
     PIdent(String),
     PAtom(char),
     PAtoms(Vec<char>),
+    PClass(String),
     PBind(String, Box<ASTParsec>),
     PCode(String),
     PMap(Box<ASTParsec>, String),
@@ -28,8 +29,15 @@ pub enum ASTParsec {
     PNot(Box<ASTParsec>),
     PTry(Box<ASTParsec>),
     PCheck(Box<ASTParsec>),
+    PRecognize(Box<ASTParsec>),
+    PLookahead(bool, Box<ASTParsec>),
     POptional(Box<ASTParsec>),
     PRepeat(bool, Box<ASTParsec>),
+    PBounded(usize, Option<usize>, Box<ASTParsec>),
+    PSeparated(bool, Box<ASTParsec>, Box<ASTParsec>),
+    PChain(bool, Box<ASTParsec>, Box<ASTParsec>),
+    PLabel(Box<ASTParsec>, String),
+    PCut(Box<ASTParsec>),
 }
 
 impl ASTParsec {
@@ -40,6 +48,8 @@ impl ASTParsec {
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ASTParsecRule {
+    pub memo: bool,
+    pub left_recursive: bool,
     pub name: String,
     pub input: String,
     pub returns: String,
@@ -116,8 +116,8 @@ where
     bencher.bytes = stream.len() as u64;
 
     bencher.iter(|| match parser.check(black_box(stream.clone())) {
-        Success(_, _, _) => (),
-        Reject(_, _) => panic!("Cannot parse stream"),
+        Success(_, _, _, _) => (),
+        Reject(_, _, _) => panic!("Cannot parse stream"),
     });
 }
 
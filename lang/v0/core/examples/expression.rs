@@ -14,6 +14,12 @@
    limitations under the License.
 */
 
+#![allow(
+    clippy::cmp_owned,
+    clippy::multiple_bound_locations,
+    clippy::redundant_closure
+)]
+
 use celma_v0_core::parser::and::{AndOperation, AndProjection};
 use celma_v0_core::parser::char::{a_char, alpha, char_in_set, digit, not_char};
 use celma_v0_core::parser::core::{eos, parser};
@@ -123,12 +129,12 @@ where
 
 fn main() {
     match number().and(eos()).left().parse(CharStream::new("123")) {
-        Success(Token::Number(ref s), _, _) if *s == 123 => println!("Ident = {}", s),
+        Success(Token::Number(ref s), _, _, _) if *s == 123 => println!("Ident = {}", s),
         _ => println!("KO"),
     }
 
     match ident().and(eos()).left().parse(CharStream::new("Toto🙃")) {
-        Success(Token::Ident(ref s), _, _) if *s == String::from("Toto🙃") => {
+        Success(Token::Ident(ref s), _, _, _) if *s == String::from("Toto🙃") => {
             println!("Ident = {}", s)
         }
         _ => println!("KO"),
@@ -139,7 +145,7 @@ fn main() {
         .left()
         .parse(CharStream::new(r#""Toto""#))
     {
-        Success(Token::String(ref s), _, _) if *s == String::from("Toto") => {
+        Success(Token::String(ref s), _, _, _) if *s == String::from("Toto") => {
             println!("Ident = {}", s)
         }
         _ => println!("KO"),
@@ -148,7 +154,7 @@ fn main() {
     match record().and(eos()).left().parse(CharStream::new(
         r#"[ "Hello" , 123 , World , [ "Hello" , 123 , World ] ]"#,
     )) {
-        Success(Token::Record(ref s), _, _) => println!("Record = {:?}", s),
+        Success(Token::Record(ref s), _, _, _) => println!("Record = {:?}", s),
         _ => println!("KO"),
     }
 }
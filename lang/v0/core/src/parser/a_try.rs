@@ -38,16 +38,16 @@ where
     fn parse(&self, s: S) -> Response<A, S> {
         let Self(p, _) = self;
         match p.parse(s) {
-            Success(v, s, c) => Success(v, s, c),
-            Reject(s, _) => Reject(s, false),
+            Success(v, s, c, h) => Success(v, s, c, h),
+            Reject(s, _, e) => Reject(s, false, e.release()),
         }
     }
 
     fn check(&self, s: S) -> Response<(), S> {
         let Self(p, _) = self;
         match p.check(s) {
-            Success(v, s, c) => Success(v, s, c),
-            Reject(s, _) => Reject(s, false),
+            Success(v, s, c, h) => Success(v, s, c, h),
+            Reject(s, _, e) => Reject(s, false, e.release()),
         }
     }
 }
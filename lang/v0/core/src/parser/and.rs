@@ -16,6 +16,7 @@
 
 use std::marker::PhantomData;
 
+use crate::parser::error::merge;
 use crate::parser::map::FMap;
 use crate::parser::map::MapOperation;
 use crate::parser::response::Response;
@@ -48,11 +49,11 @@ where
         let Self(l, r, _, _) = self;
 
         match l.parse(s) {
-            Success(a, s, ba) => match r.parse(s) {
-                Success(b, s, bb) => Success((a, b), s, ba || bb),
-                Reject(s, bb) => Reject(s, ba || bb),
+            Success(a, s, ba, ha) => match r.parse(s) {
+                Success(b, s, bb, hb) => Success((a, b), s, ba || bb, merge(ha, hb)),
+                Reject(s, bb, e) => Reject(s, ba || bb, e.merge_hint(ha)),
             },
-            Reject(s, ba) => Reject(s, ba),
+            Reject(s, ba, e) => Reject(s, ba, e),
         }
     }
 
@@ -60,11 +61,11 @@ where
         let Self(l, r, _, _) = self;
 
         match l.check(s) {
-            Success(_, s, ba) => match r.check(s) {
-                Success(_, s, bb) => Success((), s, ba || bb),
-                Reject(s, bb) => Reject(s, ba || bb),
+            Success(_, s, ba, ha) => match r.check(s) {
+                Success(_, s, bb, hb) => Success((), s, ba || bb, merge(ha, hb)),
+                Reject(s, bb, e) => Reject(s, ba || bb, e.merge_hint(ha)),
             },
-            Reject(s, ba) => Reject(s, ba),
+            Reject(s, ba, e) => Reject(s, ba, e),
         }
     }
 }
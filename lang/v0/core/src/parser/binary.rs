@@ -0,0 +1,407 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::marker::PhantomData;
+
+use crate::parser::error::{Expected, ParseError, merge};
+use crate::parser::map::MapOperation;
+use crate::parser::response::Response;
+use crate::parser::response::Response::Reject;
+use crate::parser::response::Response::Success;
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::frame_stream::FrameStream;
+use crate::stream::position::Position;
+use crate::stream::specs::Slice;
+use crate::stream::specs::Stream;
+
+fn missing<A, S>(s: S, at: &S, expected: String) -> Response<A, S>
+where
+    S: Stream,
+{
+    let found = at.next().0;
+    let partial = found.is_none() && at.is_partial();
+    let error = ParseError::new(at.position(), found, vec![Expected::Label(expected)]);
+    Reject(s, false, error.need_more_if(partial))
+}
+
+fn skip<S>(s: &S, n: usize) -> Result<S, S>
+where
+    S: Stream,
+{
+    let mut source = s.clone();
+
+    for _ in 0..n {
+        match source.next() {
+            (Some(_), next) => source = next,
+            (None, _) => return Err(source),
+        }
+    }
+
+    Ok(source)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct Bytes<const N: usize>;
+
+impl<const N: usize> Combine<[u8; N]> for Bytes<N> {}
+
+impl<const N: usize, S> Parse<[u8; N], S> for Bytes<N>
+where
+    S: Stream<Item = u8>,
+{
+    fn parse(&self, s: S) -> Response<[u8; N], S> {
+        let mut bytes = [0; N];
+        let mut source = s.clone();
+
+        for byte in bytes.iter_mut() {
+            match source.next() {
+                (Some(b), next) => {
+                    *byte = b;
+                    source = next;
+                }
+                (None, _) => return missing(s, &source, format!("{N} bytes")),
+            }
+        }
+
+        Success(bytes, source, N > 0, None)
+    }
+}
+
+pub fn bytes<const N: usize>() -> Bytes<N> {
+    Bytes
+}
+
+#[inline]
+pub fn u8<S>() -> impl Parse<u8, S> + Combine<u8>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<1>().map(|[b]| b)
+}
+
+#[inline]
+pub fn i8<S>() -> impl Parse<i8, S> + Combine<i8>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<1>().map(i8::from_be_bytes)
+}
+
+#[inline]
+pub fn u16_be<S>() -> impl Parse<u16, S> + Combine<u16>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<2>().map(u16::from_be_bytes)
+}
+
+#[inline]
+pub fn u16_le<S>() -> impl Parse<u16, S> + Combine<u16>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<2>().map(u16::from_le_bytes)
+}
+
+#[inline]
+pub fn u32_be<S>() -> impl Parse<u32, S> + Combine<u32>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<4>().map(u32::from_be_bytes)
+}
+
+#[inline]
+pub fn u32_le<S>() -> impl Parse<u32, S> + Combine<u32>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<4>().map(u32::from_le_bytes)
+}
+
+#[inline]
+pub fn u64_be<S>() -> impl Parse<u64, S> + Combine<u64>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<8>().map(u64::from_be_bytes)
+}
+
+#[inline]
+pub fn u64_le<S>() -> impl Parse<u64, S> + Combine<u64>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<8>().map(u64::from_le_bytes)
+}
+
+#[inline]
+pub fn i16_be<S>() -> impl Parse<i16, S> + Combine<i16>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<2>().map(i16::from_be_bytes)
+}
+
+#[inline]
+pub fn i16_le<S>() -> impl Parse<i16, S> + Combine<i16>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<2>().map(i16::from_le_bytes)
+}
+
+#[inline]
+pub fn i32_be<S>() -> impl Parse<i32, S> + Combine<i32>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<4>().map(i32::from_be_bytes)
+}
+
+#[inline]
+pub fn i32_le<S>() -> impl Parse<i32, S> + Combine<i32>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<4>().map(i32::from_le_bytes)
+}
+
+#[inline]
+pub fn i64_be<S>() -> impl Parse<i64, S> + Combine<i64>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<8>().map(i64::from_be_bytes)
+}
+
+#[inline]
+pub fn i64_le<S>() -> impl Parse<i64, S> + Combine<i64>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<8>().map(i64::from_le_bytes)
+}
+
+#[inline]
+pub fn f32_be<S>() -> impl Parse<f32, S> + Combine<f32>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<4>().map(f32::from_be_bytes)
+}
+
+#[inline]
+pub fn f32_le<S>() -> impl Parse<f32, S> + Combine<f32>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<4>().map(f32::from_le_bytes)
+}
+
+#[inline]
+pub fn f64_be<S>() -> impl Parse<f64, S> + Combine<f64>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<8>().map(f64::from_be_bytes)
+}
+
+#[inline]
+pub fn f64_le<S>() -> impl Parse<f64, S> + Combine<f64>
+where
+    S: Stream<Item = u8>,
+{
+    bytes::<8>().map(f64::from_le_bytes)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct Leb128<A>(PhantomData<A>);
+
+impl<A> Combine<A> for Leb128<A> {}
+
+impl<S> Parse<u64, S> for Leb128<u64>
+where
+    S: Stream<Item = u8>,
+{
+    fn parse(&self, s: S) -> Response<u64, S> {
+        match leb128(&s, false) {
+            Ok((value, _, _, source)) => Success(value, source, true, None),
+            Err(at) => missing(s, &at, "uleb128".to_string()),
+        }
+    }
+}
+
+impl<S> Parse<i64, S> for Leb128<i64>
+where
+    S: Stream<Item = u8>,
+{
+    fn parse(&self, s: S) -> Response<i64, S> {
+        match leb128(&s, true) {
+            Ok((value, shift, last, source)) => {
+                let value = if shift < 64 && last & 0x40 != 0 {
+                    value | (!0 << shift)
+                } else {
+                    value
+                };
+
+                Success(value as i64, source, true, None)
+            }
+            Err(at) => missing(s, &at, "sleb128".to_string()),
+        }
+    }
+}
+
+// Reads the 7-bit groups of a varint and returns the value, its width in bits, the last
+// byte and the stream after it. Varints longer than ten bytes, or whose tenth byte does
+// not fit in the last bit, are rejected at the byte exceeding the limit. The tenth byte
+// of a signed varint only holds the sign, i.e. 0x00 or 0x7f.
+fn leb128<S>(s: &S, signed: bool) -> Result<(u64, u32, u8, S), S>
+where
+    S: Stream<Item = u8>,
+{
+    let mut value = 0u64;
+    let mut shift = 0;
+    let mut source = s.clone();
+
+    loop {
+        let (Some(byte), next) = source.next() else {
+            return Err(source);
+        };
+        let group = (byte & 0x7f) as u64;
+
+        let overflow = match shift {
+            63 if signed => group != 0 && group != 0x7f,
+            63 => group > 1,
+            _ => shift >= 64,
+        };
+
+        if overflow {
+            return Err(source);
+        }
+
+        value |= group << shift;
+        shift += 7;
+        source = next;
+
+        if byte & 0x80 == 0 {
+            return Ok((value, shift, byte, source));
+        }
+    }
+}
+
+pub fn uleb128() -> Leb128<u64> {
+    Leb128(PhantomData)
+}
+
+pub fn sleb128() -> Leb128<i64> {
+    Leb128(PhantomData)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct Take(usize);
+
+impl<A> Combine<A> for Take {}
+
+impl<S> Parse<S::Slice, S> for Take
+where
+    S: Slice<Item = u8>,
+{
+    fn parse(&self, s: S) -> Response<S::Slice, S> {
+        let Self(n) = self;
+
+        match skip(&s, *n) {
+            Ok(source) => Success(s.slice(&source), source, *n > 0, None),
+            Err(source) => missing(s, &source, format!("{n} bytes")),
+        }
+    }
+}
+
+pub fn take(n: usize) -> Take {
+    Take(n)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct LengthPrefixed<L, P, N, A>(L, P, PhantomData<N>, PhantomData<A>)
+where
+    L: Combine<N>,
+    P: Combine<A>;
+
+impl<L, P, N, A> Combine<A> for LengthPrefixed<L, P, N, A>
+where
+    L: Combine<N>,
+    P: Combine<A>,
+{
+}
+
+impl<L, P, N, A, S> Parse<A, S> for LengthPrefixed<L, P, N, A>
+where
+    L: Parse<N, S> + Combine<N>,
+    P: Parse<A, FrameStream<S>> + Combine<A>,
+    N: TryInto<usize>,
+    S: Stream<Item = u8>,
+    S::Pos: Clone,
+{
+    fn parse(&self, s: S) -> Response<A, S> {
+        let Self(length, p, _, _) = self;
+
+        let (n, s, consumed, hint) = match length.parse(s) {
+            Success(n, s, c, h) => (n, s, c, h),
+            Reject(s, c, e) => return Reject(s, c, e),
+        };
+
+        let Ok(n) = n.try_into() else {
+            let expected = vec![Expected::Label("length".to_string())];
+            let error = ParseError::new(s.position(), s.next().0, expected);
+            return Reject(s, consumed, error.merge_hint(hint));
+        };
+
+        let end = match skip(&s, n) {
+            Ok(end) => end,
+            Err(source) => {
+                let expected = vec![Expected::Label(format!("{n} bytes"))];
+                let error = ParseError::new(source.position(), None, expected);
+                let error = error.need_more_if(source.is_partial());
+                return Reject(s, consumed, error.merge_hint(hint));
+            }
+        };
+
+        // The body only sees the bytes of the frame and its unread bytes are skipped.
+        let frame = FrameStream::new(s, end.position().offset());
+
+        match p.parse(frame) {
+            Success(a, _, c, h) => Success(a, end, consumed || c || n > 0, merge(hint, h)),
+            Reject(s, c, e) => Reject(s.stream(), consumed || c, e.merge_hint(hint)),
+        }
+    }
+}
+
+pub fn length_prefixed<L, P, N, A>(length: L, p: P) -> LengthPrefixed<L, P, N, A>
+where
+    L: Combine<N>,
+    P: Combine<A>,
+{
+    LengthPrefixed(length, p, PhantomData, PhantomData)
+}
@@ -16,6 +16,7 @@
 
 use std::marker::PhantomData;
 
+use crate::parser::error::merge;
 use crate::parser::response::Response;
 use crate::parser::response::Response::Reject;
 use crate::parser::response::Response::Success;
@@ -49,11 +50,11 @@ where
         let Self(p, f, _, _) = self;
 
         match p.parse(s) {
-            Success(a, sa, ca) => match f(a).parse(sa) {
-                Success(b, sb, cb) => Success(b, sb, ca || cb),
-                Reject(sb, c) => Reject(sb, c),
+            Success(a, sa, ca, ha) => match f(a).parse(sa) {
+                Success(b, sb, cb, hb) => Success(b, sb, ca || cb, merge(ha, hb)),
+                Reject(sb, c, e) => Reject(sb, c, e.merge_hint(ha)),
             },
-            Reject(sa, c) => Reject(sa, c),
+            Reject(sa, c, e) => Reject(sa, c, e),
         }
     }
 
@@ -61,11 +62,11 @@ where
         let Self(p, f, _, _) = self;
 
         match p.parse(s) {
-            Success(a, sa, ca) => match f(a).check(sa) {
-                Success(_, sb, cb) => Success((), sb, ca || cb),
-                Reject(sb, c) => Reject(sb, c),
+            Success(a, sa, ca, ha) => match f(a).check(sa) {
+                Success(_, sb, cb, hb) => Success((), sb, ca || cb, merge(ha, hb)),
+                Reject(sb, c, e) => Reject(sb, c, e.merge_hint(ha)),
             },
-            Reject(sa, c) => Reject(sa, c),
+            Reject(sa, c, e) => Reject(sa, c, e),
         }
     }
 }
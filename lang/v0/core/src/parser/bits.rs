@@ -0,0 +1,123 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use crate::parser::error::{Expected, ParseError};
+use crate::parser::response::Response;
+use crate::parser::response::Response::Reject;
+use crate::parser::response::Response::Success;
+use crate::parser::satisfy::Satisfy;
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::position::Position;
+use crate::stream::specs::Stream;
+
+#[inline]
+pub fn flag<S>() -> impl Parse<bool, S> + Combine<bool>
+where
+    S: Stream<Item = bool>,
+{
+    Satisfy::new((), |_, _| true).with_expected(|_| vec![Expected::Label("bit".to_string())])
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct Bits(usize);
+
+impl Combine<u64> for Bits {}
+
+impl<S> Parse<u64, S> for Bits
+where
+    S: Stream<Item = bool>,
+{
+    fn parse(&self, s: S) -> Response<u64, S> {
+        let Self(n) = self;
+
+        let mut value = 0;
+        let mut source = s.clone();
+
+        for _ in 0..*n {
+            match source.next() {
+                (Some(bit), next) => {
+                    value = (value << 1) | bit as u64;
+                    source = next;
+                }
+                (None, _) => {
+                    let expected = vec![Expected::Label(format!("{n} bits"))];
+                    let error = ParseError::new(source.position(), None, expected);
+                    return Reject(s, false, error.need_more_if(source.is_partial()));
+                }
+            }
+        }
+
+        Success(value, source, *n > 0, None)
+    }
+}
+
+/// Reads `n` bits, most significant first, as an unsigned integer of at most 64 bits.
+pub fn bits(n: usize) -> Bits {
+    assert!(n <= 64, "cannot read {n} bits in a u64");
+
+    Bits(n)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct Align(usize);
+
+impl Combine<()> for Align {}
+
+impl<S> Parse<(), S> for Align
+where
+    S: Stream<Item = bool>,
+{
+    fn parse(&self, s: S) -> Response<(), S> {
+        let Self(n) = self;
+
+        let mut consumed = false;
+        let mut source = s;
+
+        while !source.position().offset().is_multiple_of(*n) {
+            match source.next() {
+                (Some(_), next) => {
+                    consumed = true;
+                    source = next;
+                }
+                (None, _) if source.is_partial() => {
+                    let expected = vec![Expected::Label("alignment".to_string())];
+                    let error = ParseError::new(source.position(), None, expected);
+                    return Reject(source, consumed, error.need_more());
+                }
+                (None, _) => break,
+            }
+        }
+
+        Success((), source, consumed, None)
+    }
+}
+
+/// Skips the remaining bits of the current byte.
+pub fn align() -> Align {
+    Align(8)
+}
+
+/// Skips bits up to the next multiple of `n` bits.
+pub fn align_to(n: usize) -> Align {
+    assert!(n > 0, "cannot align to 0 bits");
+
+    Align(n)
+}
@@ -0,0 +1,173 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::marker::PhantomData;
+
+use crate::parser::error::merge;
+use crate::parser::response::Response;
+use crate::parser::response::Response::Reject;
+use crate::parser::response::Response::Success;
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::specs::Stream;
+
+#[derive(Copy, Clone)]
+pub struct Chain<P, Q, A, F>(bool, P, Q, PhantomData<A>, PhantomData<F>)
+where
+    P: Combine<A>,
+    Q: Combine<F>,
+    F: Fn(A, A) -> A;
+
+impl<P, Q, A, F> Combine<A> for Chain<P, Q, A, F>
+where
+    P: Combine<A>,
+    Q: Combine<F>,
+    F: Fn(A, A) -> A,
+{
+}
+
+impl<P, Q, A, F> Chain<P, Q, A, F>
+where
+    P: Combine<A>,
+    Q: Combine<F>,
+    F: Fn(A, A) -> A,
+{
+    fn left<S>(&self, s: S) -> Response<A, S>
+    where
+        P: Parse<A, S>,
+        Q: Parse<F, S>,
+        S: Stream,
+    {
+        let Self(_, p, q, _, _) = self;
+
+        let (mut value, mut source, mut consumed, mut hint) = match p.parse(s) {
+            Success(a, s, c, h) => (a, s, c, h),
+            Reject(s, c, e) => return Reject(s, c, e),
+        };
+
+        loop {
+            let (f, so, co, ho) = match q.parse(source.clone()) {
+                Success(f, s, c, h) => (f, s, c, h),
+                Reject(s, c, e) => {
+                    let e = e.merge_hint(hint);
+
+                    if c || e.committed {
+                        return Reject(s, c || consumed, e);
+                    }
+
+                    return Success(value, source, consumed, Some(e));
+                }
+            };
+
+            match p.parse(so) {
+                Success(a, s, c, h) => {
+                    value = f(value, a);
+                    source = s;
+                    consumed = consumed || co || c;
+                    hint = merge(merge(hint, ho), h);
+                }
+                Reject(s, c, e) => return Reject(s, consumed || co || c, e.merge_hint(ho)),
+            }
+        }
+    }
+
+    fn right<S>(&self, s: S) -> Response<A, S>
+    where
+        P: Parse<A, S>,
+        Q: Parse<F, S>,
+        S: Stream,
+    {
+        let Self(_, p, q, _, _) = self;
+
+        let (value, source, consumed, hint) = match p.parse(s) {
+            Success(a, s, c, h) => (a, s, c, h),
+            Reject(s, c, e) => return Reject(s, c, e),
+        };
+
+        match q.parse(source.clone()) {
+            Success(f, s, c, h) => match self.right(s) {
+                Success(a, s, d, i) => {
+                    Success(f(value, a), s, consumed || c || d, merge(merge(hint, h), i))
+                }
+                Reject(s, d, e) => Reject(s, consumed || c || d, e.merge_hint(h)),
+            },
+            Reject(s, c, e) => {
+                let e = e.merge_hint(hint);
+
+                if c || e.committed {
+                    return Reject(s, c || consumed, e);
+                }
+
+                Success(value, source, consumed, Some(e))
+            }
+        }
+    }
+}
+
+impl<P, Q, A, F, S> Parse<A, S> for Chain<P, Q, A, F>
+where
+    P: Parse<A, S> + Combine<A>,
+    Q: Parse<F, S> + Combine<F>,
+    F: Fn(A, A) -> A,
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<A, S> {
+        if self.0 { self.left(s) } else { self.right(s) }
+    }
+}
+
+pub trait ChainOperation<P, Q, A, F>
+where
+    P: Combine<A>,
+    Q: Combine<F>,
+    F: Fn(A, A) -> A,
+{
+    fn chainl1(self, op: Q) -> Chain<P, Q, A, F>;
+    fn chainr1(self, op: Q) -> Chain<P, Q, A, F>;
+}
+
+impl<P, Q, A, F> ChainOperation<P, Q, A, F> for P
+where
+    P: Combine<A>,
+    Q: Combine<F>,
+    F: Fn(A, A) -> A,
+{
+    fn chainl1(self, op: Q) -> Chain<P, Q, A, F> {
+        chainl1(self, op)
+    }
+
+    fn chainr1(self, op: Q) -> Chain<P, Q, A, F> {
+        chainr1(self, op)
+    }
+}
+
+pub fn chainl1<P, Q, A, F>(p: P, op: Q) -> Chain<P, Q, A, F>
+where
+    P: Combine<A>,
+    Q: Combine<F>,
+    F: Fn(A, A) -> A,
+{
+    Chain(true, p, op, PhantomData, PhantomData)
+}
+
+pub fn chainr1<P, Q, A, F>(p: P, op: Q) -> Chain<P, Q, A, F>
+where
+    P: Combine<A>,
+    Q: Combine<F>,
+    F: Fn(A, A) -> A,
+{
+    Chain(false, p, op, PhantomData, PhantomData)
+}
@@ -16,6 +16,9 @@
 
 use std::ops::Range;
 
+use unicode_general_category::get_general_category;
+
+use crate::parser::error::Expected;
 use crate::parser::or::OrOperation;
 use crate::parser::satisfy::Satisfy;
 use crate::parser::specs::Combine;
@@ -27,7 +30,7 @@ pub fn a_char<S>(c: char) -> impl Parse<char, S> + Combine<char>
 where
     S: Stream<Item = char>,
 {
-    Satisfy::new(c, |&v, &c| v == c)
+    Satisfy::new(c, |&v, &c| v == c).with_expected(|&c| vec![Expected::Item(c)])
 }
 
 #[inline]
@@ -44,6 +47,7 @@ where
     S: Stream<Item = char>,
 {
     Satisfy::new(r, |&v, r| r.start <= v && v <= r.end)
+        .with_expected(|r| vec![Expected::Range(r.start, r.end)])
 }
 
 #[inline]
@@ -52,6 +56,7 @@ where
     S: Stream<Item = char>,
 {
     Satisfy::new(r, |v, r| r.contains(v))
+        .with_expected(|r| r.iter().map(|&c| Expected::Item(c)).collect())
 }
 
 #[inline]
@@ -102,3 +107,80 @@ where
 {
     char_in_set(vec![' ', '\t', '\r', '\n'])
 }
+
+#[inline]
+pub fn unicode_alpha<S>() -> impl Parse<char, S> + Combine<char>
+where
+    S: Stream<Item = char>,
+{
+    Satisfy::new((), |v: &char, _| v.is_alphabetic())
+        .with_expected(|_| vec![Expected::Label("alphabetic".to_string())])
+}
+
+#[inline]
+pub fn unicode_alphanumeric<S>() -> impl Parse<char, S> + Combine<char>
+where
+    S: Stream<Item = char>,
+{
+    Satisfy::new((), |v: &char, _| v.is_alphanumeric())
+        .with_expected(|_| vec![Expected::Label("alphanumeric".to_string())])
+}
+
+#[inline]
+pub fn unicode_whitespace<S>() -> impl Parse<char, S> + Combine<char>
+where
+    S: Stream<Item = char>,
+{
+    Satisfy::new((), |v: &char, _| v.is_whitespace())
+        .with_expected(|_| vec![Expected::Label("whitespace".to_string())])
+}
+
+#[inline]
+pub fn xid_start<S>() -> impl Parse<char, S> + Combine<char>
+where
+    S: Stream<Item = char>,
+{
+    Satisfy::new((), |&v: &char, _| unicode_ident::is_xid_start(v))
+        .with_expected(|_| vec![Expected::Label("identifier start".to_string())])
+}
+
+#[inline]
+pub fn xid_continue<S>() -> impl Parse<char, S> + Combine<char>
+where
+    S: Stream<Item = char>,
+{
+    Satisfy::new((), |&v: &char, _| unicode_ident::is_xid_continue(v))
+        .with_expected(|_| vec![Expected::Label("identifier continue".to_string())])
+}
+
+// General categories accepted by `category`, their major classes being the first letters.
+const CATEGORIES: [&str; 30] = [
+    "Lu", "Ll", "Lt", "Lm", "Lo", "Mn", "Mc", "Me", "Nd", "Nl", "No", "Pc", "Pd", "Ps", "Pe", "Pi",
+    "Pf", "Po", "Sm", "Sc", "Sk", "So", "Zs", "Zl", "Zp", "Cc", "Cf", "Cs", "Co", "Cn",
+];
+
+/// Tells whether `name` is a general category or a major class accepted by `category`.
+pub fn is_category(name: &str) -> bool {
+    CATEGORIES
+        .iter()
+        .any(|c| *c == name || (name.len() == 1 && c.starts_with(name)))
+}
+
+/// Accepts a character whose general category is `name`, given by its
+/// abbreviation (`Lu`, `Nd`, ...). A single letter (`L`, `N`, ...) selects
+/// the whole major class.
+///
+/// Panics when `name` is not a general category.
+#[inline]
+pub fn category<S>(name: &'static str) -> impl Parse<char, S> + Combine<char>
+where
+    S: Stream<Item = char>,
+{
+    assert!(is_category(name), "unknown general category {name}");
+
+    Satisfy::new(name, |&v: &char, name| {
+        let abbreviation = get_general_category(v).abbreviation();
+        abbreviation == *name || (name.len() == 1 && abbreviation.starts_with(*name))
+    })
+    .with_expected(|name| vec![Expected::Label(format!("category {name}"))])
+}
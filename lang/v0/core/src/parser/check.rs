@@ -41,7 +41,7 @@ where
         let ns = s.clone();
         let start = s.position().offset();
         match p.check(s) {
-            Success(_, s, c) => {
+            Success(_, s, c, h) => {
                 let end = s.position().offset();
                 let mut v = Vec::new();
                 let mut ns = ns;
@@ -50,17 +50,17 @@ where
                     ns = nss;
                     v.push(c.unwrap());
                 }
-                Success(v, s, c)
+                Success(v, s, c, h)
             }
-            Reject(s, c) => Reject(s, c),
+            Reject(s, c, e) => Reject(s, c, e),
         }
     }
 
     fn check(&self, s: S) -> Response<(), S> {
         let Self(p, _) = self;
         match p.check(s) {
-            Success(v, s, c) => Success(v, s, c),
-            Reject(s, _) => Reject(s, false),
+            Success(v, s, c, h) => Success(v, s, c, h),
+            Reject(s, _, e) => Reject(s, false, e),
         }
     }
 }
@@ -17,6 +17,7 @@
 use std::marker::PhantomData;
 use std::rc::Rc;
 
+use crate::parser::error::{Expected, ParseError};
 use crate::parser::response::Response;
 use crate::parser::response::Response::Reject;
 use crate::parser::response::Response::Success;
@@ -52,7 +53,7 @@ where
     fn parse(&self, s: S) -> Response<A, S> {
         let Self(v) = self;
 
-        Success(v.clone(), s, false)
+        Success(v.clone(), s, false, None)
     }
 }
 
@@ -75,7 +76,9 @@ where
     S: Stream,
 {
     fn parse(&self, s: S) -> Response<A, S> {
-        Reject(s, self.0)
+        let error = ParseError::unexpected(s.position(), s.next().0);
+
+        Reject(s, self.0, error)
     }
 }
 
@@ -96,8 +99,15 @@ where
 {
     fn parse(&self, s: S) -> Response<(), S> {
         match s.next().0 {
-            Some(_) => Reject(s, false),
-            None => Success((), s, false),
+            Some(i) => {
+                let error = ParseError::new(s.position(), Some(i), vec![Expected::EndOfStream]);
+                Reject(s, false, error)
+            }
+            None if s.is_partial() => {
+                let error = ParseError::new(s.position(), None, vec![Expected::EndOfStream]);
+                Reject(s, false, error.need_more())
+            }
+            None => Success((), s, false, None),
         }
     }
 }
@@ -0,0 +1,78 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::marker::PhantomData;
+
+use crate::parser::response::Response;
+use crate::parser::response::Response::Reject;
+use crate::parser::response::Response::Success;
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::specs::Stream;
+
+#[derive(Copy, Clone)]
+pub struct Cut<P, A>(P, PhantomData<A>)
+where
+    P: Combine<A>;
+
+impl<P, A> Combine<A> for Cut<P, A> where P: Combine<A> {}
+
+impl<P, A, S> Parse<A, S> for Cut<P, A>
+where
+    P: Parse<A, S> + Combine<A>,
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<A, S> {
+        let Self(p, _) = self;
+
+        match p.parse(s) {
+            Success(v, s, c, h) => Success(v, s, c, h),
+            Reject(s, c, e) => Reject(s, c, e.commit()),
+        }
+    }
+
+    fn check(&self, s: S) -> Response<(), S> {
+        let Self(p, _) = self;
+
+        match p.check(s) {
+            Success(v, s, c, h) => Success(v, s, c, h),
+            Reject(s, c, e) => Reject(s, c, e.commit()),
+        }
+    }
+}
+
+pub trait CutOperation<P, A>
+where
+    P: Combine<A>,
+{
+    fn cut(self) -> Cut<P, A>;
+}
+
+impl<P, A> CutOperation<P, A> for P
+where
+    P: Combine<A>,
+{
+    fn cut(self) -> Cut<P, A> {
+        Cut(self, PhantomData)
+    }
+}
+
+pub fn cut<P, A>(p: P) -> Cut<P, A>
+where
+    P: Combine<A>,
+{
+    Cut(p, PhantomData)
+}
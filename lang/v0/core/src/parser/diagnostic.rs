@@ -0,0 +1,105 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::fmt::Write;
+
+use crate::parser::error::ParseError;
+use crate::stream::char_stream::CharStream;
+use crate::stream::position::Position;
+use crate::stream::specs::Stream;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Style {
+    Plain,
+    Ansi,
+}
+
+impl Style {
+    fn paint(&self, code: &str, text: &str) -> String {
+        match self {
+            Style::Plain => text.to_string(),
+            Style::Ansi => format!("\x1b[{code}m{text}\x1b[0m"),
+        }
+    }
+}
+
+const ERROR: &str = "1;31";
+const MESSAGE: &str = "1";
+const GUTTER: &str = "1;34";
+
+// -------------------------------------------------------------------------------------------------
+
+pub fn render<P>(input: &CharStream<'_, P>, error: &ParseError<char, P>, style: Style) -> String
+where
+    P: Position + Clone,
+{
+    let start = input.position();
+    let line = error.position.line_number();
+    let (text, column) = locate(input.rest(), &start, &error.position);
+    let number = line.to_string();
+    let margin = " ".repeat(number.len());
+    let caret: String = text
+        .chars()
+        .take(column)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    let mut result = String::new();
+
+    let _ = writeln!(
+        result,
+        "{}{}",
+        style.paint(ERROR, "error"),
+        style.paint(MESSAGE, &format!(": {error}"))
+    );
+    let _ = writeln!(
+        result,
+        "{margin}{} {line}:{}",
+        style.paint(GUTTER, "-->"),
+        error.position.char_number() + 1
+    );
+    let _ = writeln!(result, "{margin} {}", style.paint(GUTTER, "|"));
+    let _ = writeln!(
+        result,
+        "{} {text}",
+        style.paint(GUTTER, &format!("{number} |"))
+    );
+    let _ = writeln!(
+        result,
+        "{margin} {} {caret}{}",
+        style.paint(GUTTER, "|"),
+        style.paint(ERROR, "^")
+    );
+
+    result
+}
+
+// The input may start in the middle of a line: the first line of its rest is shifted by the
+// column the input starts at.
+fn locate<'a, P>(rest: &'a str, start: &P, position: &P) -> (&'a str, usize)
+where
+    P: Position,
+{
+    let lines = position.line_number().saturating_sub(start.line_number());
+    let text = rest.split('\n').nth(lines).unwrap_or("");
+    let column = if lines == 0 {
+        position.char_number().saturating_sub(start.char_number())
+    } else {
+        position.char_number()
+    };
+
+    (text.trim_end_matches('\r'), column)
+}
@@ -0,0 +1,182 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::stream::position::Position;
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expected<I> {
+    Item(I),
+    Range(I, I),
+    Label(String),
+    EndOfStream,
+}
+
+impl<I> Display for Expected<I>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Item(i) => write!(f, "{i:?}"),
+            Expected::Range(s, e) => write!(f, "{s:?}..{e:?}"),
+            Expected::Label(l) => write!(f, "{l}"),
+            Expected::EndOfStream => write!(f, "end of stream"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError<I, P> {
+    pub position: P,
+    pub found: Option<I>,
+    pub expected: Vec<Expected<I>>,
+    pub committed: bool,
+    pub incomplete: bool,
+}
+
+impl<I, P> ParseError<I, P> {
+    pub fn new(position: P, found: Option<I>, expected: Vec<Expected<I>>) -> Self {
+        ParseError {
+            position,
+            found,
+            expected,
+            committed: false,
+            incomplete: false,
+        }
+    }
+
+    pub fn unexpected(position: P, found: Option<I>) -> Self {
+        Self::new(position, found, Vec::new())
+    }
+
+    pub fn commit(self) -> Self {
+        ParseError {
+            committed: true,
+            ..self
+        }
+    }
+
+    pub fn release(self) -> Self {
+        ParseError {
+            committed: self.incomplete,
+            ..self
+        }
+    }
+
+    // An incomplete error is committed so no alternative is tried before more items are
+    // available, even by a backtracking or a negative parser.
+    pub fn need_more(self) -> Self {
+        ParseError {
+            committed: true,
+            incomplete: true,
+            ..self
+        }
+    }
+
+    pub fn need_more_if(self, partial: bool) -> Self {
+        if partial { self.need_more() } else { self }
+    }
+}
+
+impl<I, P> ParseError<I, P>
+where
+    P: Position,
+{
+    pub fn merge(self, other: Self) -> Self {
+        let (offset, other_offset) = (self.position.offset(), other.position.offset());
+
+        if other.incomplete {
+            other
+        } else if self.incomplete {
+            self
+        } else if other.committed {
+            other
+        } else if self.committed || offset > other_offset {
+            self
+        } else if offset < other_offset {
+            other
+        } else {
+            let mut error = self;
+            error.expected.extend(other.expected);
+            error
+        }
+    }
+
+    pub fn merge_hint(self, hint: Option<Self>) -> Self {
+        match hint {
+            Some(hint) => hint.merge(self),
+            None => self,
+        }
+    }
+}
+
+pub fn merge<I, P>(
+    l: Option<ParseError<I, P>>,
+    r: Option<ParseError<I, P>>,
+) -> Option<ParseError<I, P>>
+where
+    P: Position,
+{
+    match (l, r) {
+        (Some(l), Some(r)) => Some(l.merge(r)),
+        (l, None) => l,
+        (None, r) => r,
+    }
+}
+
+impl<I, P> Display for ParseError<I, P>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut expected = Vec::new();
+
+        for e in self.expected.iter().map(|e| e.to_string()) {
+            if !expected.contains(&e) {
+                expected.push(e);
+            }
+        }
+
+        if !expected.is_empty() {
+            write!(f, "expected ")?;
+
+            for (index, e) in expected.iter().enumerate() {
+                if index == 0 {
+                    write!(f, "{e}")?;
+                } else if index + 1 == expected.len() {
+                    write!(f, " or {e}")?;
+                } else {
+                    write!(f, ", {e}")?;
+                }
+            }
+
+            write!(f, ", found ")?;
+        } else {
+            write!(f, "unexpected ")?;
+        }
+
+        match &self.found {
+            Some(i) => write!(f, "{i:?}"),
+            None => write!(f, "end of stream"),
+        }
+    }
+}
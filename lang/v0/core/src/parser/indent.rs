@@ -0,0 +1,224 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+use std::marker::PhantomData;
+
+use crate::parser::error::{Expected, ParseError, merge};
+use crate::parser::response::Response;
+use crate::parser::response::Response::Reject;
+use crate::parser::response::Response::Success;
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::specs::Indentable;
+
+fn misaligned<A, S>(s: S, expected: String) -> Response<A, S>
+where
+    S: Indentable,
+{
+    let error = ParseError::new(s.position(), s.next().0, vec![Expected::Label(expected)]);
+    Reject(s, false, error)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct Column;
+
+impl Combine<usize> for Column {}
+
+impl<S> Parse<usize, S> for Column
+where
+    S: Indentable,
+{
+    fn parse(&self, s: S) -> Response<usize, S> {
+        Success(s.column(), s, false, None)
+    }
+}
+
+pub fn column() -> Column {
+    Column
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct IndentGuard<F>(F)
+where
+    F: Fn(usize) -> bool;
+
+impl<F> Combine<()> for IndentGuard<F> where F: Fn(usize) -> bool {}
+
+impl<F, S> Parse<(), S> for IndentGuard<F>
+where
+    F: Fn(usize) -> bool,
+    S: Indentable,
+{
+    fn parse(&self, s: S) -> Response<(), S> {
+        let Self(f) = self;
+
+        if f(s.column()) {
+            Success((), s, false, None)
+        } else {
+            misaligned(s, String::from("indentation"))
+        }
+    }
+}
+
+pub fn indent_guard<F>(f: F) -> IndentGuard<F>
+where
+    F: Fn(usize) -> bool,
+{
+    IndentGuard(f)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct SameColumn<P, A>(usize, P, PhantomData<A>)
+where
+    P: Combine<A>;
+
+impl<P, A> Combine<A> for SameColumn<P, A> where P: Combine<A> {}
+
+impl<P, A, S> Parse<A, S> for SameColumn<P, A>
+where
+    P: Parse<A, S> + Combine<A>,
+    S: Indentable,
+{
+    fn parse(&self, s: S) -> Response<A, S> {
+        let Self(column, p, _) = self;
+
+        if s.column() == *column {
+            p.parse(s)
+        } else {
+            misaligned(s, format!("column {column}"))
+        }
+    }
+}
+
+pub fn same_column<P, A>(column: usize, p: P) -> SameColumn<P, A>
+where
+    P: Combine<A>,
+{
+    SameColumn(column, p, PhantomData)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct AlignedMany<P, A>(P, PhantomData<A>)
+where
+    P: Combine<A>;
+
+impl<P, A> Combine<Vec<A>> for AlignedMany<P, A> where P: Combine<A> {}
+
+impl<P, A, S> Parse<Vec<A>, S> for AlignedMany<P, A>
+where
+    P: Parse<A, S> + Combine<A>,
+    S: Indentable,
+{
+    fn parse(&self, s: S) -> Response<Vec<A>, S> {
+        let Self(p, _) = self;
+
+        let column = s.column();
+        let (mut values, mut source, mut consumed, mut hint) = match p.parse(s) {
+            Success(a, s, c, h) => (vec![a], s, c, h),
+            Reject(s, c, e) => return Reject(s, c, e),
+        };
+
+        loop {
+            if source.column() != column {
+                return Success(values, source, consumed, hint);
+            }
+
+            match p.parse(source.clone()) {
+                Success(v, s, c, h) => {
+                    values.push(v);
+                    consumed = c || consumed;
+                    hint = merge(hint, h);
+                    source = s;
+                }
+                Reject(s, c, e) => {
+                    let e = e.merge_hint(hint);
+
+                    if c || e.committed {
+                        return Reject(s, c || consumed, e);
+                    }
+
+                    return Success(values, source, consumed, Some(e));
+                }
+            }
+        }
+    }
+}
+
+pub fn aligned_many<P, A>(p: P) -> AlignedMany<P, A>
+where
+    P: Combine<A>,
+{
+    AlignedMany(p, PhantomData)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct IndentedBlock<H, P, B, A>(H, AlignedMany<P, A>, PhantomData<B>)
+where
+    H: Combine<B>,
+    P: Combine<A>;
+
+impl<H, P, B, A> Combine<(B, Vec<A>)> for IndentedBlock<H, P, B, A>
+where
+    H: Combine<B>,
+    P: Combine<A>,
+{
+}
+
+impl<H, P, B, A, S> Parse<(B, Vec<A>), S> for IndentedBlock<H, P, B, A>
+where
+    H: Parse<B, S> + Combine<B>,
+    P: Parse<A, S> + Combine<A>,
+    S: Indentable,
+{
+    fn parse(&self, s: S) -> Response<(B, Vec<A>), S> {
+        let Self(header, items, _) = self;
+
+        let column = s.column();
+
+        match header.parse(s) {
+            Success(b, s, c, h) => {
+                if s.column() <= column {
+                    let expected = vec![Expected::Label(format!("column greater than {column}"))];
+                    let error = ParseError::new(s.position(), s.next().0, expected);
+                    return Reject(s, c, error.merge_hint(h));
+                }
+
+                match items.parse(s) {
+                    Success(a, s, d, i) => Success((b, a), s, c || d, merge(h, i)),
+                    Reject(s, d, e) => Reject(s, c || d, e.merge_hint(h)),
+                }
+            }
+            Reject(s, c, e) => Reject(s, c, e),
+        }
+    }
+}
+
+pub fn indented_block<H, P, B, A>(header: H, p: P) -> IndentedBlock<H, P, B, A>
+where
+    H: Combine<B>,
+    P: Combine<A>,
+{
+    IndentedBlock(header, aligned_many(p), PhantomData)
+}
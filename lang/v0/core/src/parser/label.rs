@@ -0,0 +1,103 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::marker::PhantomData;
+
+use crate::parser::error::{Expected, ParseError};
+use crate::parser::response::Response;
+use crate::parser::response::Response::Reject;
+use crate::parser::response::Response::Success;
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::position::Position;
+use crate::stream::specs::Stream;
+
+#[derive(Copy, Clone)]
+pub struct Label<'b, P, A>(P, &'b str, PhantomData<A>)
+where
+    P: Combine<A>;
+
+impl<P, A> Combine<A> for Label<'_, P, A> where P: Combine<A> {}
+
+impl<P, A> Label<'_, P, A>
+where
+    P: Combine<A>,
+{
+    fn relabel<I, L>(&self, start: usize, e: ParseError<I, L>) -> ParseError<I, L>
+    where
+        L: Position,
+    {
+        let Self(_, name, _) = self;
+
+        if e.position.offset() == start {
+            ParseError {
+                expected: vec![Expected::Label(name.to_string())],
+                ..e
+            }
+        } else {
+            e
+        }
+    }
+}
+
+impl<P, A, S> Parse<A, S> for Label<'_, P, A>
+where
+    P: Parse<A, S> + Combine<A>,
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<A, S> {
+        let Self(p, _, _) = self;
+        let start = s.position().offset();
+
+        match p.parse(s) {
+            Success(a, s, c, h) => Success(a, s, c, h.map(|e| self.relabel(start, e))),
+            Reject(s, c, e) => Reject(s, c, self.relabel(start, e)),
+        }
+    }
+
+    fn check(&self, s: S) -> Response<(), S> {
+        let Self(p, _, _) = self;
+        let start = s.position().offset();
+
+        match p.check(s) {
+            Success(a, s, c, h) => Success(a, s, c, h.map(|e| self.relabel(start, e))),
+            Reject(s, c, e) => Reject(s, c, self.relabel(start, e)),
+        }
+    }
+}
+
+pub trait LabelOperation<'b, P, A>
+where
+    P: Combine<A>,
+{
+    fn label(self, name: &'b str) -> Label<'b, P, A>;
+}
+
+impl<'b, P, A> LabelOperation<'b, P, A> for P
+where
+    P: Combine<A>,
+{
+    fn label(self, name: &'b str) -> Label<'b, P, A> {
+        Label(self, name, PhantomData)
+    }
+}
+
+pub fn label<P, A>(p: P, name: &str) -> Label<'_, P, A>
+where
+    P: Combine<A>,
+{
+    Label(p, name, PhantomData)
+}
@@ -14,14 +14,16 @@
    limitations under the License.
 */
 
+use std::cell::OnceCell;
 use std::marker::PhantomData;
 
 use crate::parser::response::Response;
 use crate::parser::specs::{Combine, Parse};
 use crate::stream::specs::Stream;
 
-#[derive(Copy, Clone)]
-pub struct Lazy<F, P, A>(F, PhantomData<P>, PhantomData<A>)
+// The parser is built on first use and then kept for the following ones.
+#[derive(Clone)]
+pub struct Lazy<F, P, A>(F, OnceCell<P>, PhantomData<A>)
 where
     P: Combine<A>,
     F: Fn() -> P;
@@ -40,15 +42,15 @@ where
     S: Stream,
 {
     fn parse(&self, s: S) -> Response<A, S> {
-        let Self(f, _, _) = self;
+        let Self(f, p, _) = self;
 
-        f().parse(s)
+        p.get_or_init(f).parse(s)
     }
 
     fn check(&self, s: S) -> Response<(), S> {
-        let Self(f, _, _) = self;
+        let Self(f, p, _) = self;
 
-        f().check(s)
+        p.get_or_init(f).check(s)
     }
 }
 
@@ -59,5 +61,5 @@ where
     P: Parse<A, S> + Combine<A>,
     F: Fn() -> P,
 {
-    Lazy(f, PhantomData, PhantomData)
+    Lazy(f, OnceCell::new(), PhantomData)
 }
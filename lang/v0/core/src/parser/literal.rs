@@ -16,6 +16,7 @@
 
 use crate::parser::and::AndOperation;
 use crate::parser::char::{a_char, not_char};
+use crate::parser::error::{Expected, ParseError};
 use crate::parser::map::MapOperation;
 use crate::parser::or::OrOperation;
 use crate::parser::repeat::RepeatOperation;
@@ -44,7 +45,7 @@ where
 
         loop {
             if index == v.len() {
-                return Success(v, ns, index > 0);
+                return Success(v, ns, index > 0, None);
             }
 
             let (oc, next) = ns.next();
@@ -55,15 +56,18 @@ where
                     index += 1;
                     ns = next;
                 }
-                _ => {
-                    return Reject(s, false);
+                (oc, _) => {
+                    let expected = vec![Expected::Label(format!("{v:?}"))];
+                    let partial = oc.is_none() && ns.is_partial();
+                    let error = ParseError::new(ns.position(), oc, expected);
+                    return Reject(s, false, error.need_more_if(partial));
                 }
             }
         }
     }
 }
 
-pub fn string(s: &str) -> Chars {
+pub fn string(s: &str) -> Chars<'_> {
     Chars(s)
 }
 
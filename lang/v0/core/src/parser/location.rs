@@ -57,13 +57,13 @@ where
         let start = s.position();
 
         match p.parse(s) {
-            Success(value, ns, c) => {
+            Success(value, ns, c, h) => {
                 let end = ns.position();
                 let l = Location { start, end, value };
 
-                Success(l, ns, c)
+                Success(l, ns, c, h)
             }
-            Reject(s, c) => Reject(s, c),
+            Reject(s, c, e) => Reject(s, c, e),
         }
     }
 
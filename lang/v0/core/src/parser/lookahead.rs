@@ -16,6 +16,7 @@
 
 use std::marker::PhantomData;
 
+use crate::parser::error::ParseError;
 use crate::parser::response::Response;
 use crate::parser::response::Response::Reject;
 use crate::parser::response::Response::Success;
@@ -39,8 +40,8 @@ where
         let Self(p, _) = self;
 
         match p.parse(s.clone()) {
-            Success(a, _, ba) => Success(a, s, ba),
-            Reject(s, ba) => Reject(s, ba),
+            Success(a, _, ba, _) => Success(a, s, ba, None),
+            Reject(s, ba, e) => Reject(s, ba, e),
         }
     }
 
@@ -48,8 +49,8 @@ where
         let Self(p, _) = self;
 
         match p.check(s.clone()) {
-            Success(a, _, ba) => Success(a, s, ba),
-            Reject(s, ba) => Reject(s, ba),
+            Success(a, _, ba, _) => Success(a, s, ba, None),
+            Reject(s, ba, e) => Reject(s, ba, e),
         }
     }
 }
@@ -62,3 +63,78 @@ where
 {
     Lookahead(p, PhantomData)
 }
+
+#[derive(Copy, Clone)]
+pub struct FollowedBy<P, A>(P, PhantomData<A>)
+where
+    P: Combine<A>;
+
+impl<P, A> Combine<A> for FollowedBy<P, A> where P: Combine<A> {}
+
+impl<P, A, S> Parse<A, S> for FollowedBy<P, A>
+where
+    P: Parse<A, S> + Combine<A>,
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<A, S> {
+        let Self(p, _) = self;
+
+        match p.parse(s.clone()) {
+            Success(a, _, _, _) => Success(a, s, false, None),
+            Reject(_, _, e) => Reject(s, false, e.release()),
+        }
+    }
+
+    fn check(&self, s: S) -> Response<(), S> {
+        let Self(p, _) = self;
+
+        match p.check(s.clone()) {
+            Success(_, _, _, _) => Success((), s, false, None),
+            Reject(_, _, e) => Reject(s, false, e.release()),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct NotFollowedBy<P, A>(P, PhantomData<A>)
+where
+    P: Combine<A>;
+
+impl<P, A> Combine<()> for NotFollowedBy<P, A> where P: Combine<A> {}
+
+impl<P, A, S> Parse<(), S> for NotFollowedBy<P, A>
+where
+    P: Parse<A, S> + Combine<A>,
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<(), S> {
+        self.check(s)
+    }
+
+    fn check(&self, s: S) -> Response<(), S> {
+        let Self(p, _) = self;
+
+        match p.check(s.clone()) {
+            Success(_, _, _, _) => {
+                let error = ParseError::unexpected(s.position(), s.next().0);
+                Reject(s, false, error)
+            }
+            Reject(_, _, e) if e.incomplete => Reject(s, false, e),
+            Reject(_, _, _) => Success((), s, false, None),
+        }
+    }
+}
+
+pub fn followed_by<P, A>(p: P) -> FollowedBy<P, A>
+where
+    P: Combine<A>,
+{
+    FollowedBy(p, PhantomData)
+}
+
+pub fn not_followed_by<P, A>(p: P) -> NotFollowedBy<P, A>
+where
+    P: Combine<A>,
+{
+    NotFollowedBy(p, PhantomData)
+}
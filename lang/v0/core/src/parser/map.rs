@@ -46,8 +46,8 @@ where
         let Self(p, f, _, _) = self;
 
         match p.parse(s) {
-            Success(a, s, c) => Success(f(a), s, c),
-            Reject(s, c) => Reject(s, c),
+            Success(a, s, c, h) => Success(f(a), s, c, h),
+            Reject(s, c, e) => Reject(s, c, e),
         }
     }
 
@@ -55,8 +55,8 @@ where
         let Self(p, _, _, _) = self;
 
         match p.check(s) {
-            Success(_, s, c) => Success((), s, c),
-            Reject(s, c) => Reject(s, c),
+            Success(_, s, c, h) => Success((), s, c, h),
+            Reject(s, c, e) => Reject(s, c, e),
         }
     }
 }
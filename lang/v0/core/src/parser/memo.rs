@@ -0,0 +1,150 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+use std::marker::PhantomData;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::parser::error::{Expected, ParseError};
+use crate::parser::response::Response;
+use crate::parser::response::Response::{Reject, Success};
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::position::Position;
+use crate::stream::specs::Stream;
+
+static KEYS: AtomicUsize = AtomicUsize::new(0);
+
+fn fresh_key() -> usize {
+    KEYS.fetch_add(1, Ordering::Relaxed)
+}
+
+#[derive(Copy, Clone)]
+pub struct Memo<P, A>(bool, usize, P, PhantomData<A>)
+where
+    P: Combine<A>;
+
+impl<P, A> Combine<A> for Memo<P, A> where P: Combine<A> {}
+
+impl<P, A, S> Parse<A, S> for Memo<P, A>
+where
+    A: Clone + 'static,
+    P: Parse<A, S> + Combine<A>,
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<A, S> {
+        let Self(grow, key, p, _) = self;
+
+        if let Some(response) = s.recall(*key) {
+            return response;
+        }
+
+        if *grow {
+            return self.grow(s);
+        }
+
+        let response = p.parse(s.clone());
+        s.memoize(*key, &response);
+        response
+    }
+}
+
+impl<P, A> Memo<P, A>
+where
+    P: Combine<A>,
+{
+    // Seed growing: the recursive call first fails on the seed then sees the last successful
+    // response, and the rule is parsed again until it no longer consumes more input.
+    fn grow<S>(&self, s: S) -> Response<A, S>
+    where
+        A: Clone + 'static,
+        P: Parse<A, S>,
+        S: Stream,
+    {
+        let Self(_, key, p, _) = self;
+
+        let seed = ParseError::new(s.position(), s.next().0, vec![]);
+        s.memoize::<A>(*key, &Reject(s.clone(), false, seed));
+
+        if s.recall::<A>(*key).is_none() {
+            let expected = vec![Expected::Label(String::from("memo stream"))];
+            return Reject(
+                s.clone(),
+                false,
+                ParseError::new(s.position(), s.next().0, expected),
+            );
+        }
+
+        let mut response = p.parse(s.clone());
+
+        loop {
+            s.memoize(*key, &response);
+
+            let offset = match &response {
+                Success(_, ns, _, _) => ns.position().offset(),
+                Reject(_, _, _) => return response,
+            };
+
+            match p.parse(s.clone()) {
+                Success(a, ns, c, h) if ns.position().offset() > offset => {
+                    response = Success(a, ns, c, h)
+                }
+                _ => return response,
+            }
+        }
+    }
+}
+
+pub struct MemoKey(OnceLock<usize>);
+
+impl MemoKey {
+    pub const fn new() -> Self {
+        MemoKey(OnceLock::new())
+    }
+
+    pub fn memo<P, A>(&self, p: P) -> Memo<P, A>
+    where
+        P: Combine<A>,
+    {
+        Memo(false, *self.0.get_or_init(fresh_key), p, PhantomData)
+    }
+
+    pub fn left_rec<P, A>(&self, p: P) -> Memo<P, A>
+    where
+        P: Combine<A>,
+    {
+        Memo(true, *self.0.get_or_init(fresh_key), p, PhantomData)
+    }
+}
+
+impl Default for MemoKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn memo<P, A>(p: P) -> Memo<P, A>
+where
+    P: Combine<A>,
+{
+    Memo(false, fresh_key(), p, PhantomData)
+}
+
+pub fn left_rec<P, A>(p: P) -> Memo<P, A>
+where
+    P: Combine<A>,
+{
+    Memo(true, fresh_key(), p, PhantomData)
+}
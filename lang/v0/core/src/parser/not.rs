@@ -16,6 +16,7 @@
 
 use std::marker::PhantomData;
 
+use crate::parser::error::ParseError;
 use crate::parser::response::Response;
 use crate::parser::response::Response::Reject;
 use crate::parser::response::Response::Success;
@@ -39,10 +40,17 @@ where
         let Self(p, _) = self;
 
         match p.parse(s.clone()) {
-            Success(_, s, _) => Reject(s, false),
+            Success(_, ns, _, _) => {
+                let error = ParseError::unexpected(s.position(), s.next().0);
+                Reject(ns, false, error)
+            }
+            Reject(ns, c, e) if e.incomplete => Reject(ns, c, e),
             _ => match s.next() {
-                (Some(v), s) => Success(v, s, true),
-                _ => Reject(s, false),
+                (Some(v), s) => Success(v, s, true, None),
+                (None, ns) => {
+                    let error = ParseError::unexpected(s.position(), None);
+                    Reject(ns, false, error.need_more_if(s.is_partial()))
+                }
             },
         }
     }
@@ -51,10 +59,17 @@ where
         let Self(p, _) = self;
 
         match p.check(s.clone()) {
-            Success(_, s, _) => Reject(s, false),
+            Success(_, ns, _, _) => {
+                let error = ParseError::unexpected(s.position(), s.next().0);
+                Reject(ns, false, error)
+            }
+            Reject(ns, c, e) if e.incomplete => Reject(ns, c, e),
             _ => match s.next() {
-                (Some(_), s) => Success((), s, true),
-                _ => Reject(s, false),
+                (Some(_), s) => Success((), s, true, None),
+                (None, ns) => {
+                    let error = ParseError::unexpected(s.position(), None);
+                    Reject(ns, false, error.need_more_if(s.is_partial()))
+                }
             },
         }
     }
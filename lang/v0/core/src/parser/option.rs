@@ -39,12 +39,12 @@ where
         let Self(p, _) = self;
 
         match p.parse(s.clone()) {
-            Success(v, s, c) => Success(Some(v), s, c),
-            Reject(ns, c) => {
-                if c {
-                    Reject(ns, c)
+            Success(v, s, c, h) => Success(Some(v), s, c, h),
+            Reject(ns, c, e) => {
+                if c || e.committed {
+                    Reject(ns, c, e)
                 } else {
-                    Success(None, s, false)
+                    Success(None, s, false, Some(e))
                 }
             }
         }
@@ -54,12 +54,12 @@ where
         let Self(p, _) = self;
 
         match p.check(s.clone()) {
-            Success(v, s, c) => Success(v, s, c),
-            Reject(ns, c) => {
-                if c {
-                    Reject(ns, c)
+            Success(v, s, c, h) => Success(v, s, c, h),
+            Reject(ns, c, e) => {
+                if c || e.committed {
+                    Reject(ns, c, e)
                 } else {
-                    Success((), s, false)
+                    Success((), s, false, Some(e))
                 }
             }
         }
@@ -16,8 +16,10 @@
 
 use std::marker::PhantomData;
 
+use crate::parser::error::merge;
 use crate::parser::response::Response;
 use crate::parser::response::Response::Reject;
+use crate::parser::response::Response::Success;
 use crate::parser::specs::Combine;
 use crate::parser::specs::Parse;
 use crate::stream::specs::Stream;
@@ -45,7 +47,10 @@ where
         let Self(l, r, _) = self;
 
         match l.parse(s.clone()) {
-            Reject(_, false) => r.parse(s),
+            Reject(_, false, e) if !e.committed => match r.parse(s) {
+                Success(v, s, c, h) => Success(v, s, c, merge(Some(e), h)),
+                Reject(s, c, f) => Reject(s, c, e.merge(f)),
+            },
             r => r,
         }
     }
@@ -54,7 +59,10 @@ where
         let Self(l, r, _) = self;
 
         match l.check(s.clone()) {
-            Reject(_, false) => r.check(s),
+            Reject(_, false, e) if !e.committed => match r.check(s) {
+                Success(v, s, c, h) => Success(v, s, c, merge(Some(e), h)),
+                Reject(s, c, f) => Reject(s, c, e.merge(f)),
+            },
             r => r,
         }
     }
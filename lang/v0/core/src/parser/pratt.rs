@@ -0,0 +1,193 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::marker::PhantomData;
+
+use crate::parser::error::merge;
+use crate::parser::response::Response;
+use crate::parser::response::Response::Reject;
+use crate::parser::response::Response::Success;
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::specs::Stream;
+
+type PrefixFn<O, E> = fn(O, E) -> E;
+
+pub enum Fixity<O, E> {
+    Prefix(PrefixFn<O, E>),
+    InfixLeft(fn(E, O, E) -> E),
+    InfixRight(fn(E, O, E) -> E),
+    Postfix(fn(E, O) -> E),
+}
+
+impl<O, E> Clone for Fixity<O, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O, E> Copy for Fixity<O, E> {}
+
+#[derive(Clone)]
+pub struct Pratt<P, Q, E, O>(P, Q, Vec<(O, usize, Fixity<O, E>)>, PhantomData<E>)
+where
+    P: Combine<E>,
+    Q: Combine<O>;
+
+impl<P, Q, E, O> Pratt<P, Q, E, O>
+where
+    P: Combine<E>,
+    Q: Combine<O>,
+{
+    pub fn prefix(self, o: O, power: usize, f: PrefixFn<O, E>) -> Self {
+        self.operator(o, power, Fixity::Prefix(f))
+    }
+
+    pub fn infix_left(self, o: O, power: usize, f: fn(E, O, E) -> E) -> Self {
+        self.operator(o, power, Fixity::InfixLeft(f))
+    }
+
+    pub fn infix_right(self, o: O, power: usize, f: fn(E, O, E) -> E) -> Self {
+        self.operator(o, power, Fixity::InfixRight(f))
+    }
+
+    pub fn postfix(self, o: O, power: usize, f: fn(E, O) -> E) -> Self {
+        self.operator(o, power, Fixity::Postfix(f))
+    }
+
+    pub fn operator(self, o: O, power: usize, fixity: Fixity<O, E>) -> Self {
+        let Self(p, q, mut operators, e) = self;
+        operators.push((o, power, fixity));
+
+        Pratt(p, q, operators, e)
+    }
+}
+
+impl<P, Q, E, O> Pratt<P, Q, E, O>
+where
+    P: Combine<E>,
+    Q: Combine<O>,
+    O: PartialEq,
+{
+    fn prefix_of(&self, o: &O) -> Option<(usize, PrefixFn<O, E>)> {
+        self.2.iter().find_map(|(v, power, fixity)| match fixity {
+            Fixity::Prefix(f) if v == o => Some((*power, *f)),
+            _ => None,
+        })
+    }
+
+    fn suffix_of(&self, o: &O) -> Option<(usize, Fixity<O, E>)> {
+        self.2.iter().find_map(|(v, power, fixity)| match fixity {
+            Fixity::Prefix(_) => None,
+            _ if v == o => Some((*power, *fixity)),
+            _ => None,
+        })
+    }
+
+    fn expression<S>(&self, s: S, min_power: usize) -> Response<E, S>
+    where
+        P: Parse<E, S>,
+        Q: Parse<O, S>,
+        S: Stream,
+    {
+        let Self(p, q, _, _) = self;
+
+        let (mut lhs, mut source, mut consumed, mut hint) = match q.parse(s.clone()) {
+            Success(o, so, co, ho) if self.prefix_of(&o).is_some() => {
+                let (power, f) = self.prefix_of(&o).unwrap();
+
+                match self.expression(so, power) {
+                    Success(e, s, c, h) => (f(o, e), s, co || c, merge(ho, h)),
+                    Reject(s, c, e) => return Reject(s, co || c, e.merge_hint(ho)),
+                }
+            }
+            Reject(s, c, e) if c || e.committed => return Reject(s, c, e),
+            _ => match p.parse(s) {
+                Success(e, s, c, h) => (e, s, c, h),
+                Reject(s, c, e) => return Reject(s, c, e),
+            },
+        };
+
+        loop {
+            let (o, so, co, ho) = match q.parse(source.clone()) {
+                Success(o, s, c, h) => (o, s, c, h),
+                Reject(s, c, e) if c || e.committed => {
+                    return Reject(s, consumed || c, e.merge_hint(hint));
+                }
+                Reject(_, _, e) => {
+                    return Success(lhs, source, consumed, merge(hint, Some(e)));
+                }
+            };
+
+            let (power, fixity) = match self.suffix_of(&o) {
+                Some((power, fixity)) if power >= min_power => (power, fixity),
+                _ => return Success(lhs, source, consumed, hint),
+            };
+
+            let (next_power, f) = match fixity {
+                Fixity::Postfix(f) => {
+                    lhs = f(lhs, o);
+                    source = so;
+                    consumed = consumed || co;
+                    hint = merge(hint, ho);
+                    continue;
+                }
+                Fixity::InfixLeft(f) => (power + 1, f),
+                Fixity::InfixRight(f) => (power, f),
+                Fixity::Prefix(_) => unreachable!(),
+            };
+
+            match self.expression(so, next_power) {
+                Success(rhs, s, c, h) => {
+                    lhs = f(lhs, o, rhs);
+                    source = s;
+                    consumed = consumed || co || c;
+                    hint = merge(merge(hint, ho), h);
+                }
+                Reject(s, c, e) => {
+                    return Reject(s, consumed || co || c, e.merge_hint(ho));
+                }
+            }
+        }
+    }
+}
+
+impl<P, Q, E, O> Combine<E> for Pratt<P, Q, E, O>
+where
+    P: Combine<E>,
+    Q: Combine<O>,
+{
+}
+
+impl<P, Q, E, O, S> Parse<E, S> for Pratt<P, Q, E, O>
+where
+    P: Parse<E, S> + Combine<E>,
+    Q: Parse<O, S> + Combine<O>,
+    O: PartialEq,
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<E, S> {
+        self.expression(s, 0)
+    }
+}
+
+pub fn pratt<P, Q, E, O>(operand: P, operator: Q) -> Pratt<P, Q, E, O>
+where
+    P: Combine<E>,
+    Q: Combine<O>,
+{
+    Pratt(operand, operator, Vec::new(), PhantomData)
+}
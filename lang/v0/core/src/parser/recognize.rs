@@ -0,0 +1,61 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::marker::PhantomData;
+
+use crate::parser::response::Response;
+use crate::parser::response::Response::Reject;
+use crate::parser::response::Response::Success;
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::specs::Slice;
+
+#[derive(Copy, Clone)]
+pub struct Recognize<L, A>(L, PhantomData<A>)
+where
+    L: Combine<A>;
+
+impl<L, A, B> Combine<A> for Recognize<L, B> where L: Combine<B> {}
+
+impl<L, B, S> Parse<S::Slice, S> for Recognize<L, B>
+where
+    L: Parse<B, S> + Combine<B>,
+    S: Slice,
+{
+    fn parse(&self, s: S) -> Response<S::Slice, S> {
+        let Self(p, _) = self;
+        let start = s.clone();
+
+        match p.check(s) {
+            Success(_, s, c, h) => Success(start.slice(&s), s, c, h),
+            Reject(s, c, e) => Reject(s, c, e),
+        }
+    }
+
+    fn check(&self, s: S) -> Response<(), S> {
+        let Self(p, _) = self;
+
+        p.check(s)
+    }
+}
+
+pub fn recognize<P, B, S>(p: P) -> impl Parse<S::Slice, S> + Combine<S::Slice>
+where
+    S: Slice,
+    P: Parse<B, S> + Combine<B>,
+{
+    Recognize(p, PhantomData)
+}
@@ -0,0 +1,193 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::marker::PhantomData;
+
+use crate::parser::error::{Expected, ParseError};
+use crate::parser::response::Response;
+use crate::parser::response::Response::Reject;
+use crate::parser::response::Response::Success;
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::specs::Recoverable;
+use crate::stream::specs::Stream;
+
+#[derive(Copy, Clone)]
+pub struct Recover<P, R, A, B>(P, R, PhantomData<A>, PhantomData<B>)
+where
+    P: Combine<A>,
+    R: Combine<B>;
+
+impl<P, R, A, B> Combine<Option<A>> for Recover<P, R, A, B>
+where
+    P: Combine<A>,
+    R: Combine<B>,
+{
+}
+
+impl<P, R, A, B, S> Parse<Option<A>, S> for Recover<P, R, A, B>
+where
+    P: Parse<A, S> + Combine<A>,
+    R: Parse<B, S> + Combine<B>,
+    S: Recoverable,
+{
+    fn parse(&self, s: S) -> Response<Option<A>, S> {
+        let Self(p, r, _, _) = self;
+
+        match p.parse(s.clone()) {
+            Success(a, s, c, h) => Success(Some(a), s, c, h),
+            Reject(sp, cp, e) if e.incomplete => Reject(sp, cp, e),
+            Reject(sp, cp, e) => match r.check(s) {
+                Success(_, s, true, _) => Success(None, s.record(e), true, None),
+                _ => Reject(sp, cp, e),
+            },
+        }
+    }
+}
+
+pub trait RecoveryOperation<P, R, A, B>
+where
+    P: Combine<A>,
+    R: Combine<B>,
+{
+    fn recover_with(self, r: R) -> Recover<P, R, A, B>;
+}
+
+impl<P, R, A, B> RecoveryOperation<P, R, A, B> for P
+where
+    P: Combine<A>,
+    R: Combine<B>,
+{
+    fn recover_with(self, r: R) -> Recover<P, R, A, B> {
+        Recover(self, r, PhantomData, PhantomData)
+    }
+}
+
+pub fn recover_with<P, R, A, B>(p: P, r: R) -> Recover<P, R, A, B>
+where
+    P: Combine<A>,
+    R: Combine<B>,
+{
+    Recover(p, r, PhantomData, PhantomData)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct SkipUntil<P, A>(P, PhantomData<A>)
+where
+    P: Combine<A>;
+
+impl<P, A> Combine<()> for SkipUntil<P, A> where P: Combine<A> {}
+
+impl<P, A, S> Parse<(), S> for SkipUntil<P, A>
+where
+    P: Parse<A, S> + Combine<A>,
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<(), S> {
+        let Self(p, _) = self;
+
+        let mut consumed = false;
+        let mut source = s;
+
+        loop {
+            match p.check(source.clone()) {
+                Success(_, _, _, _) => return Success((), source, consumed, None),
+                Reject(_, _, e) if e.incomplete => return Reject(source, consumed, e),
+                Reject(_, _, _) => {}
+            }
+
+            match source.next() {
+                (Some(_), s) => {
+                    consumed = true;
+                    source = s;
+                }
+                (None, _) => return Success((), source, consumed, None),
+            }
+        }
+    }
+}
+
+pub fn skip_until<P, A>(p: P) -> SkipUntil<P, A>
+where
+    P: Combine<A>,
+{
+    SkipUntil(p, PhantomData)
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct DelimitedRecovery<I>(I, I);
+
+impl<I> Combine<()> for DelimitedRecovery<I> {}
+
+impl<I, S> Parse<(), S> for DelimitedRecovery<I>
+where
+    I: PartialEq + Clone,
+    S: Stream<Item = I>,
+{
+    fn parse(&self, s: S) -> Response<(), S> {
+        let Self(open, close) = self;
+
+        match s.next() {
+            (Some(c), mut source) if c == *open => {
+                let mut depth = 1;
+
+                loop {
+                    match source.next() {
+                        (Some(c), s) => {
+                            if c == *open {
+                                depth += 1;
+                            } else if c == *close {
+                                depth -= 1;
+                            }
+
+                            source = s;
+
+                            if depth == 0 {
+                                return Success((), source, true, None);
+                            }
+                        }
+                        (None, _) => {
+                            let error = ParseError::new(
+                                source.position(),
+                                None,
+                                vec![Expected::Item(close.clone())],
+                            )
+                            .need_more_if(source.is_partial());
+
+                            return Reject(source, true, error);
+                        }
+                    }
+                }
+            }
+            (c, _) => {
+                let error = ParseError::new(s.position(), c, vec![Expected::Item(open.clone())]);
+
+                Reject(s, false, error)
+            }
+        }
+    }
+}
+
+pub fn delimited_recovery<I>(open: I, close: I) -> DelimitedRecovery<I>
+where
+    I: PartialEq + Clone,
+{
+    DelimitedRecovery(open, close)
+}
@@ -0,0 +1,158 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+use std::cell::OnceCell;
+use std::rc::{Rc, Weak};
+
+use crate::parser::core::{Parser, parser};
+use crate::parser::error::ParseError;
+use crate::parser::response::Response;
+use crate::parser::response::Response::Reject;
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::specs::Stream;
+
+type Knot<'a, A, S> = OnceCell<Parser<'a, A, S>>;
+
+trait Held {}
+
+impl<T> Held for T {}
+
+pub struct Recursive<'a, A, S>(Rc<Knot<'a, A, S>>, Vec<Rc<dyn Held + 'a>>)
+where
+    S: Stream;
+
+impl<'a, A, S> Recursive<'a, A, S>
+where
+    S: Stream,
+{
+    pub fn new() -> Self {
+        Recursive(Rc::new(OnceCell::new()), Vec::new())
+    }
+
+    pub fn reference(&self) -> RecursiveRef<'a, A, S> {
+        RecursiveRef(Rc::downgrade(&self.0))
+    }
+
+    pub fn define<P>(&self, p: P)
+    where
+        P: Parse<A, S> + 'a,
+    {
+        let _ = self.0.set(parser(p));
+    }
+
+    pub fn hold<B>(mut self, other: Recursive<'a, B, S>) -> Self
+    where
+        A: 'a,
+        B: 'a,
+        S: 'a,
+    {
+        self.1.push(other.0);
+        self.1.extend(other.1);
+        self
+    }
+}
+
+impl<A, S> Default for Recursive<'_, A, S>
+where
+    S: Stream,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, S> Clone for Recursive<'_, A, S>
+where
+    S: Stream,
+{
+    fn clone(&self) -> Self {
+        Recursive(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<A, S> Combine<A> for Recursive<'_, A, S> where S: Stream {}
+
+impl<A, S> Parse<A, S> for Recursive<'_, A, S>
+where
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<A, S> {
+        match self.0.get() {
+            Some(p) => p.parse(s),
+            None => undefined(s),
+        }
+    }
+
+    fn check(&self, s: S) -> Response<(), S> {
+        match self.0.get() {
+            Some(p) => p.check(s),
+            None => undefined(s),
+        }
+    }
+}
+
+pub struct RecursiveRef<'a, A, S>(Weak<Knot<'a, A, S>>)
+where
+    S: Stream;
+
+impl<A, S> Clone for RecursiveRef<'_, A, S>
+where
+    S: Stream,
+{
+    fn clone(&self) -> Self {
+        RecursiveRef(self.0.clone())
+    }
+}
+
+impl<A, S> Combine<A> for RecursiveRef<'_, A, S> where S: Stream {}
+
+impl<A, S> Parse<A, S> for RecursiveRef<'_, A, S>
+where
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<A, S> {
+        match self.0.upgrade().as_deref().and_then(OnceCell::get) {
+            Some(p) => p.parse(s),
+            None => undefined(s),
+        }
+    }
+
+    fn check(&self, s: S) -> Response<(), S> {
+        match self.0.upgrade().as_deref().and_then(OnceCell::get) {
+            Some(p) => p.check(s),
+            None => undefined(s),
+        }
+    }
+}
+
+fn undefined<A, S>(s: S) -> Response<A, S>
+where
+    S: Stream,
+{
+    let error = ParseError::unexpected(s.position(), s.next().0);
+    Reject(s, false, error)
+}
+
+pub fn recursive<'a, F, P, A, S>(f: F) -> Recursive<'a, A, S>
+where
+    F: FnOnce(RecursiveRef<'a, A, S>) -> P,
+    P: Parse<A, S> + 'a,
+    S: Stream,
+{
+    let rule = Recursive::new();
+    rule.define(f(rule.reference()));
+    rule
+}
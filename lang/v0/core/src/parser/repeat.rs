@@ -16,6 +16,7 @@
 
 use std::marker::PhantomData;
 
+use crate::parser::error::merge;
 use crate::parser::response::Response;
 use crate::parser::response::Response::Reject;
 use crate::parser::response::Response::Success;
@@ -24,7 +25,7 @@ use crate::parser::specs::Parse;
 use crate::stream::specs::Stream;
 
 #[derive(Copy, Clone)]
-pub struct Repeat<L, A>(bool, L, PhantomData<A>)
+pub struct Repeat<L, A>(usize, Option<usize>, L, PhantomData<A>)
 where
     L: Combine<A>;
 
@@ -36,58 +37,74 @@ where
     S: Stream,
 {
     fn parse(&self, s: S) -> Response<Vec<A>, S> {
-        let Self(can_be_empty, p, _) = self;
+        let Self(min, max, p, _) = self;
 
         let mut values = Vec::new();
         let mut consumed = false;
+        let mut hint = None;
         let mut source = s;
 
         loop {
+            if Some(values.len()) == *max {
+                return Success(values, source, consumed, hint);
+            }
+
             match p.parse(source.clone()) {
-                Success(v, s, c) => {
+                Success(v, s, c, h) => {
                     values.push(v);
                     consumed = c || consumed;
+                    hint = merge(hint, h);
                     source = s;
                 }
-                Reject(s, c) => {
-                    if c {
-                        return Reject(s, c);
+                Reject(s, c, e) => {
+                    let e = e.merge_hint(hint);
+
+                    if c || e.committed {
+                        return Reject(s, c, e);
                     }
 
-                    if !*can_be_empty && values.is_empty() {
-                        return Reject(s, consumed);
+                    if values.len() < *min {
+                        return Reject(s, consumed, e);
                     }
 
-                    return Success(values, source, consumed);
+                    return Success(values, source, consumed, Some(e));
                 }
             }
         }
     }
 
     fn check(&self, s: S) -> Response<(), S> {
-        let Self(can_be_empty, p, _) = self;
+        let Self(min, max, p, _) = self;
 
-        let mut empty = true;
+        let mut count = 0;
         let mut consumed = false;
+        let mut hint = None;
         let mut source = s;
 
         loop {
+            if Some(count) == *max {
+                return Success((), source, consumed, hint);
+            }
+
             match p.check(source.clone()) {
-                Success(_, s, c) => {
-                    empty = false;
+                Success(_, s, c, h) => {
+                    count += 1;
                     consumed = c || consumed;
+                    hint = merge(hint, h);
                     source = s;
                 }
-                Reject(s, c) => {
-                    if c {
-                        return Reject(s, c);
+                Reject(s, c, e) => {
+                    let e = e.merge_hint(hint);
+
+                    if c || e.committed {
+                        return Reject(s, c, e);
                     }
 
-                    if !*can_be_empty && empty {
-                        return Reject(s, consumed);
+                    if count < *min {
+                        return Reject(s, consumed, e);
                     }
 
-                    return Success((), source, consumed);
+                    return Success((), source, consumed, Some(e));
                 }
             }
         }
@@ -100,6 +117,13 @@ where
 {
     fn rep(self) -> Repeat<L, A>;
     fn opt_rep(self) -> Repeat<L, A>;
+    fn times(self, n: usize) -> Repeat<L, A>;
+    fn at_least(self, n: usize) -> Repeat<L, A>;
+    fn between(self, n: usize, m: usize) -> Repeat<L, A>;
+    fn fold_rep<B, F>(self, init: B, f: F) -> FoldRep<L, A, B, F>
+    where
+        B: Clone,
+        F: Fn(B, A) -> B;
 }
 
 impl<L, A> RepeatOperation<L, A> for L
@@ -107,10 +131,283 @@ where
     L: Combine<A>,
 {
     fn rep(self) -> Repeat<L, A> {
-        Repeat(false, self, PhantomData)
+        Repeat(1, None, self, PhantomData)
     }
 
     fn opt_rep(self) -> Repeat<L, A> {
-        Repeat(true, self, PhantomData)
+        Repeat(0, None, self, PhantomData)
     }
+
+    fn times(self, n: usize) -> Repeat<L, A> {
+        Repeat(n, Some(n), self, PhantomData)
+    }
+
+    fn at_least(self, n: usize) -> Repeat<L, A> {
+        Repeat(n, None, self, PhantomData)
+    }
+
+    fn between(self, n: usize, m: usize) -> Repeat<L, A> {
+        assert!(n <= m, "cannot repeat between {n} and {m} times");
+
+        Repeat(n, Some(m), self, PhantomData)
+    }
+
+    fn fold_rep<B, F>(self, init: B, f: F) -> FoldRep<L, A, B, F>
+    where
+        B: Clone,
+        F: Fn(B, A) -> B,
+    {
+        FoldRep(self, init, f, PhantomData)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct FoldRep<L, A, B, F>(L, B, F, PhantomData<A>)
+where
+    L: Combine<A>,
+    B: Clone,
+    F: Fn(B, A) -> B;
+
+impl<L, A, B, F> Combine<B> for FoldRep<L, A, B, F>
+where
+    L: Combine<A>,
+    B: Clone,
+    F: Fn(B, A) -> B,
+{
+}
+
+impl<L, A, B, F, S> Parse<B, S> for FoldRep<L, A, B, F>
+where
+    L: Parse<A, S> + Combine<A>,
+    B: Clone,
+    F: Fn(B, A) -> B,
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<B, S> {
+        let Self(p, init, f, _) = self;
+
+        let mut value = init.clone();
+        let mut consumed = false;
+        let mut hint = None;
+        let mut source = s;
+
+        loop {
+            match p.parse(source.clone()) {
+                Success(v, s, c, h) => {
+                    value = f(value, v);
+                    consumed = c || consumed;
+                    hint = merge(hint, h);
+                    source = s;
+                }
+                Reject(s, c, e) => {
+                    let e = e.merge_hint(hint);
+
+                    if c || e.committed {
+                        return Reject(s, c, e);
+                    }
+
+                    return Success(value, source, consumed, Some(e));
+                }
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone)]
+pub struct SkipMany<L, A>(Repeat<L, A>)
+where
+    L: Combine<A>;
+
+impl<L, A> Combine<()> for SkipMany<L, A> where L: Combine<A> {}
+
+impl<L, A, S> Parse<(), S> for SkipMany<L, A>
+where
+    L: Parse<A, S> + Combine<A>,
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<(), S> {
+        self.0.check(s)
+    }
+}
+
+pub fn skip_many<L, A>(p: L) -> SkipMany<L, A>
+where
+    L: Combine<A>,
+{
+    SkipMany(p.opt_rep())
+}
+
+pub fn skip_many1<L, A>(p: L) -> SkipMany<L, A>
+where
+    L: Combine<A>,
+{
+    SkipMany(p.rep())
+}
+
+// -------------------------------------------------------------------------------------------------
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Trailing {
+    Forbidden,
+    Optional,
+    Required,
+}
+
+#[derive(Copy, Clone)]
+pub struct SepBy<L, R, A, B>(bool, Trailing, L, R, PhantomData<A>, PhantomData<B>)
+where
+    L: Combine<A>,
+    R: Combine<B>;
+
+impl<L, R, A, B> SepBy<L, R, A, B>
+where
+    L: Combine<A>,
+    R: Combine<B>,
+{
+    pub fn with_trailing(self, trailing: Trailing) -> Self {
+        let Self(can_be_empty, _, p, sep, a, b) = self;
+
+        SepBy(can_be_empty, trailing, p, sep, a, b)
+    }
+}
+
+impl<L, R, A, B> Combine<Vec<A>> for SepBy<L, R, A, B>
+where
+    L: Combine<A>,
+    R: Combine<B>,
+{
+}
+
+impl<L, R, A, B, S> Parse<Vec<A>, S> for SepBy<L, R, A, B>
+where
+    L: Parse<A, S> + Combine<A>,
+    R: Parse<B, S> + Combine<B>,
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<Vec<A>, S> {
+        let Self(can_be_empty, trailing, p, sep, _, _) = self;
+
+        let mut values = Vec::new();
+        let mut consumed = false;
+        let mut hint = None;
+        let mut source = s;
+
+        loop {
+            match p.parse(source.clone()) {
+                Success(v, s, c, h) => {
+                    values.push(v);
+                    consumed = c || consumed;
+                    hint = merge(hint, h);
+                    source = s;
+                }
+                Reject(s, c, e) => {
+                    let e = e.merge_hint(hint);
+
+                    if c || e.committed {
+                        return Reject(s, c, e);
+                    }
+
+                    if values.is_empty() {
+                        if *can_be_empty {
+                            return Success(values, source, consumed, Some(e));
+                        }
+
+                        return Reject(s, consumed, e);
+                    }
+
+                    if *trailing == Trailing::Forbidden {
+                        return Reject(s, consumed, e);
+                    }
+
+                    return Success(values, source, consumed, Some(e));
+                }
+            }
+
+            match sep.parse(source.clone()) {
+                Success(_, s, c, h) => {
+                    consumed = c || consumed;
+                    hint = merge(hint, h);
+                    source = s;
+                }
+                Reject(s, c, e) => {
+                    let e = e.merge_hint(hint);
+
+                    if c || e.committed || *trailing == Trailing::Required {
+                        return Reject(s, c || consumed, e);
+                    }
+
+                    return Success(values, source, consumed, Some(e));
+                }
+            }
+        }
+    }
+}
+
+pub trait SepByOperation<L, R, A, B>
+where
+    L: Combine<A>,
+    R: Combine<B>,
+{
+    fn sep_by(self, sep: R) -> SepBy<L, R, A, B>;
+    fn sep_by1(self, sep: R) -> SepBy<L, R, A, B>;
+    fn end_by(self, sep: R) -> SepBy<L, R, A, B>;
+    fn sep_end_by(self, sep: R) -> SepBy<L, R, A, B>;
+}
+
+impl<L, R, A, B> SepByOperation<L, R, A, B> for L
+where
+    L: Combine<A>,
+    R: Combine<B>,
+{
+    fn sep_by(self, sep: R) -> SepBy<L, R, A, B> {
+        sep_by(self, sep)
+    }
+
+    fn sep_by1(self, sep: R) -> SepBy<L, R, A, B> {
+        sep_by1(self, sep)
+    }
+
+    fn end_by(self, sep: R) -> SepBy<L, R, A, B> {
+        end_by(self, sep)
+    }
+
+    fn sep_end_by(self, sep: R) -> SepBy<L, R, A, B> {
+        sep_end_by(self, sep)
+    }
+}
+
+pub fn sep_by<L, R, A, B>(p: L, sep: R) -> SepBy<L, R, A, B>
+where
+    L: Combine<A>,
+    R: Combine<B>,
+{
+    SepBy(true, Trailing::Forbidden, p, sep, PhantomData, PhantomData)
+}
+
+pub fn sep_by1<L, R, A, B>(p: L, sep: R) -> SepBy<L, R, A, B>
+where
+    L: Combine<A>,
+    R: Combine<B>,
+{
+    SepBy(false, Trailing::Forbidden, p, sep, PhantomData, PhantomData)
+}
+
+pub fn end_by<L, R, A, B>(p: L, sep: R) -> SepBy<L, R, A, B>
+where
+    L: Combine<A>,
+    R: Combine<B>,
+{
+    SepBy(true, Trailing::Required, p, sep, PhantomData, PhantomData)
+}
+
+pub fn sep_end_by<L, R, A, B>(p: L, sep: R) -> SepBy<L, R, A, B>
+where
+    L: Combine<A>,
+    R: Combine<B>,
+{
+    SepBy(true, Trailing::Optional, p, sep, PhantomData, PhantomData)
 }
@@ -14,6 +14,7 @@
    limitations under the License.
 */
 
+use crate::parser::error::{ParseError, merge};
 use crate::stream::specs::Stream;
 
 #[derive(Debug)]
@@ -21,8 +22,8 @@ pub enum Response<A, S>
 where
     S: Stream,
 {
-    Success(A, S, bool),
-    Reject(S, bool),
+    Success(A, S, bool, Option<ParseError<S::Item, S::Pos>>),
+    Reject(S, bool, ParseError<S::Item, S::Pos>),
 }
 
 impl<A, S> Response<A, S>
@@ -35,17 +36,55 @@ where
         FR: Fn(S, bool) -> B,
     {
         match self {
-            Response::Success(a, s, b) => success(a, s, b),
-            Response::Reject(s, b) => reject(s, b),
+            Response::Success(a, s, b, _) => success(a, s, b),
+            Response::Reject(s, b, _) => reject(s, b),
         }
     }
     pub fn map<F, B>(self, f: F) -> Response<B, S>
     where
         F: Fn(A) -> B,
     {
-        self.fold(
-            |a, s, b| Response::Success(f(a), s, b),
-            |s, b| Response::Reject(s, b),
-        )
+        match self {
+            Response::Success(a, s, b, h) => Response::Success(f(a), s, b, h),
+            Response::Reject(s, b, e) => Response::Reject(s, b, e),
+        }
+    }
+
+    pub fn and_then<F, B>(self, f: F) -> Response<B, S>
+    where
+        F: Fn(A, S) -> Response<B, S>,
+    {
+        match self {
+            Response::Success(a, s, b, h) => match f(a, s) {
+                Response::Success(a, s, c, i) => Response::Success(a, s, b || c, merge(h, i)),
+                Response::Reject(s, c, e) => Response::Reject(s, b || c, e.merge_hint(h)),
+            },
+            Response::Reject(s, b, e) => Response::Reject(s, b, e),
+        }
+    }
+
+    pub fn map_err<F>(self, f: F) -> Response<A, S>
+    where
+        F: Fn(ParseError<S::Item, S::Pos>) -> ParseError<S::Item, S::Pos>,
+    {
+        match self {
+            Response::Success(a, s, b, h) => Response::Success(a, s, b, h),
+            Response::Reject(s, b, e) => Response::Reject(s, b, f(e)),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, Response::Success(_, _, _, _))
+    }
+
+    pub fn is_incomplete(&self) -> bool {
+        matches!(self, Response::Reject(_, _, e) if e.incomplete)
+    }
+
+    pub fn into_result(self) -> Result<A, ParseError<S::Item, S::Pos>> {
+        match self {
+            Response::Success(a, _, _, _) => Ok(a),
+            Response::Reject(_, _, e) => Err(e),
+        }
     }
 }
@@ -16,6 +16,7 @@
 
 use std::marker::PhantomData;
 
+use crate::parser::error::{Expected, ParseError};
 use crate::parser::response::Response;
 use crate::parser::response::Response::Reject;
 use crate::parser::response::Response::Success;
@@ -24,7 +25,7 @@ use crate::parser::specs::Parse;
 use crate::stream::specs::Stream;
 
 #[derive(Copy, Clone)]
-pub struct Satisfy<E, I, C>(C, E, PhantomData<I>, PhantomData<C>)
+pub struct Satisfy<E, I, C>(C, E, fn(&C) -> Vec<Expected<I>>, PhantomData<C>)
 where
     E: Fn(&I, &C) -> bool;
 
@@ -36,7 +37,13 @@ where
     where
         E: Fn(&I, &C) -> bool,
     {
-        Satisfy(c, e, PhantomData, PhantomData)
+        Satisfy(c, e, |_| Vec::new(), PhantomData)
+    }
+
+    pub fn with_expected(self, d: fn(&C) -> Vec<Expected<I>>) -> Satisfy<E, I, C> {
+        let Self(c, e, _, _) = self;
+
+        Satisfy(c, e, d, PhantomData)
     }
 }
 
@@ -48,32 +55,40 @@ where
     S: Stream<Item = I>,
 {
     fn parse(&self, s: S) -> Response<I, S> {
-        let Self(c, predicate, _, _) = self;
+        let Self(c, predicate, expected, _) = self;
 
         match s.next() {
             (Some(i), p) => {
                 if predicate(&i, c) {
-                    Success(i, p, true)
+                    Success(i, p, true, None)
                 } else {
-                    Reject(p, false)
+                    let error = ParseError::new(s.position(), Some(i), expected(c));
+                    Reject(p, false, error)
                 }
             }
-            (None, p) => Reject(p, false),
+            (None, p) => {
+                let error = ParseError::new(s.position(), None, expected(c));
+                Reject(p, false, error.need_more_if(s.is_partial()))
+            }
         }
     }
 
     fn check(&self, s: S) -> Response<(), S> {
-        let Self(c, predicate, _, _) = self;
+        let Self(c, predicate, expected, _) = self;
 
         match s.next() {
             (Some(i), p) => {
                 if predicate(&i, c) {
-                    Success((), p, true)
+                    Success((), p, true, None)
                 } else {
-                    Reject(p, false)
+                    let error = ParseError::new(s.position(), Some(i), expected(c));
+                    Reject(p, false, error)
                 }
             }
-            (None, p) => Reject(p, false),
+            (None, p) => {
+                let error = ParseError::new(s.position(), None, expected(c));
+                Reject(p, false, error.need_more_if(s.is_partial()))
+            }
         }
     }
 }
@@ -93,5 +108,5 @@ where
     E: Eq + Copy,
     S: Stream<Item = E>,
 {
-    Satisfy::new(c, |&v, &c| v == c)
+    Satisfy::new(c, |&v, &c| v == c).with_expected(|&c| vec![Expected::Item(c)])
 }
@@ -14,8 +14,10 @@
  * limitations under the License.
  */
 
+use crate::parser::error::{Expected, ParseError};
 use crate::parser::response::Response;
 use crate::parser::response::Response::{Reject, Success};
+use crate::stream::char_stream::CharStream;
 use crate::stream::specs::Stream;
 
 pub trait Combine<A> {}
@@ -28,8 +30,40 @@ where
 
     fn check(&self, s: S) -> Response<(), S> {
         match self.parse(s) {
-            Success(_, s, c) => Success((), s, c),
-            Reject(s, c) => Reject(s, c),
+            Success(_, s, c, h) => Success((), s, c, h),
+            Reject(s, c, e) => Reject(s, c, e),
         }
     }
 }
+
+pub fn parse_all<P, A, S>(p: &P, s: S) -> Result<A, ParseError<S::Item, S::Pos>>
+where
+    P: Parse<A, S>,
+    S: Stream,
+{
+    match p.parse(s) {
+        Success(a, s, _, h) => match s.next() {
+            (None, _) if s.is_partial() => {
+                let error = ParseError::new(s.position(), None, vec![Expected::EndOfStream]);
+
+                Err(error.need_more().merge_hint(h))
+            }
+            (None, _) => Ok(a),
+            (c, _) => {
+                let error = ParseError::new(s.position(), c, vec![Expected::EndOfStream]);
+
+                Err(error.merge_hint(h))
+            }
+        },
+        Reject(_, _, e) => Err(e),
+    }
+}
+
+type StrPosition = (usize, usize, usize, usize);
+
+pub fn parse_str<'a, P, A>(p: &P, s: &'a str) -> Result<A, ParseError<char, StrPosition>>
+where
+    P: Parse<A, CharStream<'a, StrPosition>>,
+{
+    parse_all(p, CharStream::new(s))
+}
@@ -0,0 +1,88 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+use std::marker::PhantomData;
+
+use crate::parser::response::Response;
+use crate::parser::response::Response::Success;
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::specs::Stateful;
+
+#[derive(Copy, Clone)]
+pub struct GetState<U>(PhantomData<U>);
+
+impl<U> Combine<U> for GetState<U> {}
+
+impl<U, S> Parse<U, S> for GetState<U>
+where
+    U: Clone,
+    S: Stateful<State = U>,
+{
+    fn parse(&self, s: S) -> Response<U, S> {
+        Success(s.state().clone(), s, false, None)
+    }
+}
+
+pub fn get_state<U>() -> GetState<U> {
+    GetState(PhantomData)
+}
+
+#[derive(Copy, Clone)]
+pub struct PutState<U>(U);
+
+impl<U> Combine<()> for PutState<U> {}
+
+impl<U, S> Parse<(), S> for PutState<U>
+where
+    U: Clone,
+    S: Stateful<State = U>,
+{
+    fn parse(&self, s: S) -> Response<(), S> {
+        let Self(state) = self;
+
+        Success((), s.with_state(state.clone()), false, None)
+    }
+}
+
+pub fn put_state<U>(state: U) -> PutState<U> {
+    PutState(state)
+}
+
+#[derive(Copy, Clone)]
+pub struct UpdateState<F, U>(F, PhantomData<U>)
+where
+    F: Fn(&U) -> U;
+
+impl<F, U> Combine<()> for UpdateState<F, U> where F: Fn(&U) -> U {}
+
+impl<F, U, S> Parse<(), S> for UpdateState<F, U>
+where
+    F: Fn(&U) -> U,
+    S: Stateful<State = U>,
+{
+    fn parse(&self, s: S) -> Response<(), S> {
+        let Self(f, _) = self;
+
+        Success((), s.with_state(f(s.state())), false, None)
+    }
+}
+
+pub fn update_state<F, U>(f: F) -> UpdateState<F, U>
+where
+    F: Fn(&U) -> U,
+{
+    UpdateState(f, PhantomData)
+}
@@ -0,0 +1,160 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+use std::marker::PhantomData;
+
+use crate::parser::error::{ParseError, merge};
+use crate::parser::response::Response;
+use crate::parser::response::Response::Reject;
+use crate::parser::response::Response::Success;
+use crate::parser::specs::Combine;
+use crate::parser::specs::Parse;
+use crate::stream::specs::Stream;
+
+#[derive(Copy, Clone)]
+pub struct ManyTill<P, E, A, B>(P, E, PhantomData<A>, PhantomData<B>)
+where
+    P: Combine<A>,
+    E: Combine<B>;
+
+impl<P, E, A, B> Combine<(Vec<A>, B)> for ManyTill<P, E, A, B>
+where
+    P: Combine<A>,
+    E: Combine<B>,
+{
+}
+
+impl<P, E, A, B, S> Parse<(Vec<A>, B), S> for ManyTill<P, E, A, B>
+where
+    P: Parse<A, S> + Combine<A>,
+    E: Parse<B, S> + Combine<B>,
+    S: Stream,
+{
+    fn parse(&self, s: S) -> Response<(Vec<A>, B), S> {
+        let Self(p, end, _, _) = self;
+
+        let mut values = Vec::new();
+        let mut consumed = false;
+        let mut hint = None;
+        let mut source = s;
+
+        loop {
+            let error = match end.parse(source.clone()) {
+                Success(b, s, c, h) => {
+                    return Success((values, b), s, consumed || c, merge(hint, h));
+                }
+                Reject(s, c, e) => {
+                    if c || e.committed {
+                        return Reject(s, consumed || c, e.merge_hint(hint));
+                    }
+                    e
+                }
+            };
+
+            match p.parse(source.clone()) {
+                Success(v, s, c, h) => {
+                    values.push(v);
+                    consumed = consumed || c;
+                    hint = merge(merge(hint, Some(error)), h);
+                    source = s;
+                }
+                Reject(s, c, e) => {
+                    return Reject(s, consumed || c, e.merge(error).merge_hint(hint));
+                }
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct TakeUntil<E, B>(E, PhantomData<B>)
+where
+    E: Combine<B>;
+
+impl<E, A, B> Combine<Vec<A>> for TakeUntil<E, B> where E: Combine<B> {}
+
+impl<E, A, B, S> Parse<Vec<A>, S> for TakeUntil<E, B>
+where
+    E: Parse<B, S> + Combine<B>,
+    S: Stream<Item = A>,
+{
+    fn parse(&self, s: S) -> Response<Vec<A>, S> {
+        let Self(end, _) = self;
+
+        let mut values = Vec::new();
+        let mut source = s;
+
+        loop {
+            let error = match end.check(source.clone()) {
+                Success(_, _, _, _) => {
+                    let consumed = !values.is_empty();
+                    return Success(values, source, consumed, None);
+                }
+                Reject(s, c, e) => {
+                    if c || e.committed {
+                        return Reject(s, c || !values.is_empty(), e);
+                    }
+                    e
+                }
+            };
+
+            match source.next() {
+                (Some(v), s) => {
+                    values.push(v);
+                    source = s;
+                }
+                (None, s) => {
+                    let e = ParseError::unexpected(source.position(), None)
+                        .need_more_if(source.is_partial())
+                        .merge(error);
+                    return Reject(s, !values.is_empty(), e);
+                }
+            }
+        }
+    }
+}
+
+pub trait ManyTillOperation<P, E, A, B>
+where
+    P: Combine<A>,
+    E: Combine<B>,
+{
+    fn many_till(self, end: E) -> ManyTill<P, E, A, B>;
+}
+
+impl<P, E, A, B> ManyTillOperation<P, E, A, B> for P
+where
+    P: Combine<A>,
+    E: Combine<B>,
+{
+    fn many_till(self, end: E) -> ManyTill<P, E, A, B> {
+        many_till(self, end)
+    }
+}
+
+pub fn many_till<P, E, A, B>(p: P, end: E) -> ManyTill<P, E, A, B>
+where
+    P: Combine<A>,
+    E: Combine<B>,
+{
+    ManyTill(p, end, PhantomData, PhantomData)
+}
+
+pub fn take_until<E, B>(end: E) -> TakeUntil<E, B>
+where
+    E: Combine<B>,
+{
+    TakeUntil(end, PhantomData)
+}
@@ -16,7 +16,10 @@
 
 use crate::stream::end_line::EndLine;
 use crate::stream::position::Position;
+use crate::stream::specs::Indentable;
 use crate::stream::specs::Len;
+use crate::stream::specs::Slice;
+use crate::stream::specs::Stateless;
 use crate::stream::specs::Stream;
 
 #[derive(Copy, Clone)]
@@ -70,6 +73,13 @@ where
     }
 }
 
+impl<A, P> Stateless for ArrayStream<'_, A, P>
+where
+    A: EndLine + Clone,
+    P: Position + Clone,
+{
+}
+
 impl<A, P> Len for ArrayStream<'_, A, P>
 where
     A: EndLine,
@@ -79,3 +89,25 @@ where
         self.0.len()
     }
 }
+
+impl<A, P> Indentable for ArrayStream<'_, A, P>
+where
+    A: EndLine + Clone,
+    P: Position + Clone,
+{
+    fn column(&self) -> usize {
+        self.1.char_number()
+    }
+}
+
+impl<'a, A, P> Slice for ArrayStream<'a, A, P>
+where
+    A: EndLine + Clone,
+    P: Position + Clone,
+{
+    type Slice = &'a [A];
+
+    fn slice(&self, end: &Self) -> Self::Slice {
+        &self.0[self.1.offset()..end.1.offset()]
+    }
+}
@@ -0,0 +1,88 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use crate::stream::position::Position;
+use crate::stream::specs::Len;
+use crate::stream::specs::Stateless;
+use crate::stream::specs::Stream;
+
+/// Stream of the bits of a byte slice, most significant bit first. The position is the
+/// bit offset.
+#[derive(Copy, Clone)]
+pub struct BitStream<'a, P>(&'a [u8], usize, P)
+where
+    P: Position;
+
+impl<'a> BitStream<'a, usize> {
+    pub fn new(v: &'a [u8]) -> Self {
+        Self::new_with_position(v, <usize>::new())
+    }
+}
+
+impl<'a, P> BitStream<'a, P>
+where
+    P: Position,
+{
+    pub fn new_with_position(v: &'a [u8], p: P) -> Self {
+        Self(v, 0, p)
+    }
+
+    pub fn source(&self) -> &'a [u8] {
+        self.0
+    }
+
+    pub fn bit_offset(&self) -> usize {
+        self.1
+    }
+
+    pub fn is_aligned(&self) -> bool {
+        self.1.is_multiple_of(8)
+    }
+}
+
+impl<P> Stream for BitStream<'_, P>
+where
+    P: Position + Clone,
+{
+    type Item = bool;
+    type Pos = P;
+
+    fn position(&self) -> Self::Pos {
+        self.2.clone()
+    }
+
+    fn next(&self) -> (Option<Self::Item>, Self) {
+        match self.0.get(self.1 / 8) {
+            Some(byte) => {
+                let bit = byte & (0x80 >> (self.1 % 8)) != 0;
+
+                (Some(bit), BitStream(self.0, self.1 + 1, self.2.step(false)))
+            }
+            None => (None, self.clone()),
+        }
+    }
+}
+
+impl<P> Stateless for BitStream<'_, P> where P: Position + Clone {}
+
+impl<P> Len for BitStream<'_, P>
+where
+    P: Position,
+{
+    fn len(&self) -> usize {
+        self.0.len() * 8 - self.1
+    }
+}
@@ -0,0 +1,107 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use crate::stream::end_line::EndLine;
+use crate::stream::position::Position;
+use crate::stream::specs::Len;
+use crate::stream::specs::Slice;
+use crate::stream::specs::Stateless;
+use crate::stream::specs::Stream;
+
+/// Stream of bytes where the position is the byte offset. Binary data has no line
+/// so `\n` is not a line break unless `with_end_lines` is set.
+#[derive(Copy, Clone)]
+pub struct ByteStream<'a, P>(&'a [u8], usize, P, bool)
+where
+    P: Position;
+
+impl<'a> ByteStream<'a, usize> {
+    pub fn new(v: &'a [u8]) -> Self {
+        Self::new_with_position(v, <usize>::new())
+    }
+}
+
+impl<'a, P> ByteStream<'a, P>
+where
+    P: Position,
+{
+    pub fn new_with_position(v: &'a [u8], p: P) -> Self {
+        Self(v, 0, p, false)
+    }
+
+    pub fn with_end_lines(self, end_lines: bool) -> Self {
+        let Self(v, o, p, _) = self;
+
+        Self(v, o, p, end_lines)
+    }
+
+    pub fn source(&self) -> &'a [u8] {
+        self.0
+    }
+
+    pub fn rest(&self) -> &'a [u8] {
+        &self.0[self.1..]
+    }
+}
+
+impl<P> Stream for ByteStream<'_, P>
+where
+    P: Position + Clone,
+{
+    type Item = u8;
+    type Pos = P;
+
+    fn position(&self) -> Self::Pos {
+        self.2.clone()
+    }
+
+    fn next(&self) -> (Option<Self::Item>, Self) {
+        let option = self.0.get(self.1).copied();
+
+        if let Some(value) = option {
+            let end_line = self.3 && value.is_end_line();
+
+            (
+                option,
+                ByteStream(self.0, self.1 + 1, self.2.step(end_line), self.3),
+            )
+        } else {
+            (None, self.clone())
+        }
+    }
+}
+
+impl<P> Stateless for ByteStream<'_, P> where P: Position + Clone {}
+
+impl<P> Len for ByteStream<'_, P>
+where
+    P: Position,
+{
+    fn len(&self) -> usize {
+        self.0.len() - self.1
+    }
+}
+
+impl<'a, P> Slice for ByteStream<'a, P>
+where
+    P: Position + Clone,
+{
+    type Slice = &'a [u8];
+
+    fn slice(&self, end: &Self) -> Self::Slice {
+        &self.0[self.1..end.1]
+    }
+}
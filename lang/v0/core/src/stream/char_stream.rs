@@ -15,17 +15,20 @@
 */
 
 use crate::stream::position::Position;
+use crate::stream::specs::Indentable;
 use crate::stream::specs::Len;
+use crate::stream::specs::Slice;
+use crate::stream::specs::Stateless;
 use crate::stream::specs::Stream;
 
 #[derive(Clone)]
-pub struct CharStream<'a, P>(&'a str, P)
+pub struct CharStream<'a, P>(&'a str, usize, P)
 where
     P: Position;
 
-impl<'a> CharStream<'a, (usize, usize, usize)> {
+impl<'a> CharStream<'a, (usize, usize, usize, usize)> {
     pub fn new(v: &'a str) -> Self {
-        Self::new_with_position(v, <(usize, usize, usize)>::new())
+        Self::new_with_position(v, <(usize, usize, usize, usize)>::new())
     }
 }
 
@@ -34,7 +37,19 @@ where
     P: Position,
 {
     pub fn new_with_position(v: &'a str, p: P) -> Self {
-        Self(v, p)
+        Self(v, 0, p)
+    }
+
+    pub fn source(&self) -> &'a str {
+        self.0
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.0[self.1..]
+    }
+
+    pub fn byte_offset(&self) -> usize {
+        self.1
     }
 }
 
@@ -46,31 +61,50 @@ where
     type Pos = P;
 
     fn position(&self) -> Self::Pos {
-        self.1.clone()
+        self.2.clone()
     }
 
     fn next(&self) -> (Option<Self::Item>, Self) {
-        let option = self.0.chars().next();
+        let option = self.rest().chars().next();
 
         if let Some(c) = option {
             (
                 option,
-                CharStream(
-                    self.0.get(1..self.0.len()).unwrap_or(""),
-                    self.1.step(c == '\n'),
-                ),
+                CharStream(self.0, self.1 + c.len_utf8(), self.2.step_char(c)),
             )
         } else {
-            (None, CharStream(self.0, self.1.clone()))
+            (None, self.clone())
         }
     }
 }
 
+impl<P> Stateless for CharStream<'_, P> where P: Position + Clone {}
+
 impl<P> Len for CharStream<'_, P>
 where
     P: Position,
 {
     fn len(&self) -> usize {
-        self.0.len()
+        self.0.len() - self.1
+    }
+}
+
+impl<P> Indentable for CharStream<'_, P>
+where
+    P: Position + Clone,
+{
+    fn column(&self) -> usize {
+        self.2.char_number()
+    }
+}
+
+impl<'a, P> Slice for CharStream<'a, P>
+where
+    P: Position + Clone,
+{
+    type Slice = &'a str;
+
+    fn slice(&self, end: &Self) -> Self::Slice {
+        &self.0[self.1..end.1]
     }
 }
@@ -0,0 +1,132 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use crate::parser::response::Response;
+use crate::stream::memo_stream;
+use crate::stream::memo_stream::Layer;
+use crate::stream::position::Position;
+use crate::stream::specs::Len;
+use crate::stream::specs::Slice;
+use crate::stream::specs::Stream;
+
+/// Stream ending at a given offset of the underlying stream. It delimits the frame of a
+/// length-prefixed body.
+#[derive(Clone)]
+pub struct FrameStream<S>(S, usize)
+where
+    S: Stream;
+
+impl<S> FrameStream<S>
+where
+    S: Stream,
+{
+    pub fn new(s: S, end: usize) -> Self {
+        FrameStream(s, end)
+    }
+
+    pub fn stream(self) -> S {
+        self.0
+    }
+}
+
+impl<S> Stream for FrameStream<S>
+where
+    S: Stream,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    type Item = S::Item;
+    type Pos = S::Pos;
+
+    fn position(&self) -> Self::Pos {
+        self.0.position()
+    }
+
+    fn next(&self) -> (Option<Self::Item>, Self) {
+        if self.0.position().offset() < self.1 {
+            let (item, s) = self.0.next();
+
+            (item, FrameStream(s, self.1))
+        } else {
+            (None, self.clone())
+        }
+    }
+
+    // The frame waits for more items only before its end.
+    fn is_partial(&self) -> bool {
+        self.0.position().offset() < self.1 && self.0.is_partial()
+    }
+
+    fn recall<A>(&self, key: usize) -> Option<Response<A, Self>>
+    where
+        A: Clone + 'static,
+    {
+        memo_stream::recall(self, key)
+    }
+
+    fn memoize<A>(&self, key: usize, response: &Response<A, Self>)
+    where
+        A: Clone + 'static,
+    {
+        memo_stream::memoize(self, key, response)
+    }
+}
+
+impl<S> Layer for FrameStream<S>
+where
+    S: Stream,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    type Inner = S;
+    type Data = usize;
+
+    fn split(&self) -> (&Self::Inner, &Self::Data) {
+        (&self.0, &self.1)
+    }
+
+    fn join(s: Self::Inner, data: Self::Data) -> Self {
+        FrameStream(s, data)
+    }
+
+    fn same(&self, data: &Self::Data) -> bool {
+        self.1 == *data
+    }
+}
+
+impl<S> Len for FrameStream<S>
+where
+    S: Stream,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    fn len(&self) -> usize {
+        self.1.saturating_sub(self.0.position().offset())
+    }
+}
+
+impl<S> Slice for FrameStream<S>
+where
+    S: Slice,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    type Slice = S::Slice;
+
+    fn slice(&self, end: &Self) -> Self::Slice {
+        self.0.slice(&end.0)
+    }
+}
@@ -20,6 +20,7 @@ use std::marker::PhantomData;
 use crate::stream::end_line::EndLine;
 use crate::stream::position::Position;
 use crate::stream::specs::Len;
+use crate::stream::specs::Stateless;
 use crate::stream::specs::Stream;
 
 #[derive(Clone)]
@@ -83,6 +84,14 @@ where
     }
 }
 
+impl<E, I, P> Stateless for IteratorStream<E, I, P>
+where
+    I: Iterator<Item = E> + Clone,
+    E: EndLine + Clone,
+    P: Position + Clone,
+{
+}
+
 impl<E, I, P> Len for IteratorStream<E, I, P>
 where
     I: Iterator<Item = E> + Clone,
@@ -0,0 +1,228 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::parser::error::ParseError;
+use crate::parser::response::Response;
+use crate::parser::response::Response::{Reject, Success};
+use crate::stream::position::Position;
+use crate::stream::specs::Indentable;
+use crate::stream::specs::Len;
+use crate::stream::specs::Recoverable;
+use crate::stream::specs::Slice;
+use crate::stream::specs::Stateless;
+use crate::stream::specs::Stream;
+
+enum Entry<S>
+where
+    S: Stream,
+{
+    Success(Rc<dyn Any>, S, bool, Option<ParseError<S::Item, S::Pos>>),
+    Reject(S, bool, ParseError<S::Item, S::Pos>),
+}
+
+type Table<S> = RefCell<HashMap<(usize, usize), Entry<S>>>;
+
+pub struct MemoStream<S>(S, Rc<Table<S>>)
+where
+    S: Stream;
+
+impl<S> MemoStream<S>
+where
+    S: Stateless,
+{
+    pub fn new(s: S) -> Self {
+        MemoStream(s, Rc::new(RefCell::new(HashMap::new())))
+    }
+}
+
+impl<S> MemoStream<S>
+where
+    S: Stream,
+{
+    fn wrap(&self, s: S) -> Self {
+        MemoStream(s, self.1.clone())
+    }
+}
+
+impl<S> Clone for MemoStream<S>
+where
+    S: Stream,
+{
+    fn clone(&self) -> Self {
+        MemoStream(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<S> Stream for MemoStream<S>
+where
+    S: Stream,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    type Item = S::Item;
+    type Pos = S::Pos;
+
+    fn position(&self) -> Self::Pos {
+        self.0.position()
+    }
+
+    fn next(&self) -> (Option<Self::Item>, Self) {
+        let (item, s) = self.0.next();
+
+        (item, self.wrap(s))
+    }
+
+    fn is_partial(&self) -> bool {
+        self.0.is_partial()
+    }
+
+    fn recall<A>(&self, key: usize) -> Option<Response<A, Self>>
+    where
+        A: Clone + 'static,
+    {
+        let table = self.1.borrow();
+
+        match table.get(&(key, self.position().offset()))? {
+            Entry::Success(a, s, c, h) => {
+                let a = a.downcast_ref::<A>()?.clone();
+                Some(Success(a, self.wrap(s.clone()), *c, h.clone()))
+            }
+            Entry::Reject(s, c, e) => Some(Reject(self.wrap(s.clone()), *c, e.clone())),
+        }
+    }
+
+    fn memoize<A>(&self, key: usize, response: &Response<A, Self>)
+    where
+        A: Clone + 'static,
+    {
+        let entry = match response {
+            Success(a, s, c, h) => Entry::Success(Rc::new(a.clone()), s.0.clone(), *c, h.clone()),
+            Reject(s, c, e) => Entry::Reject(s.0.clone(), *c, e.clone()),
+        };
+
+        self.1
+            .borrow_mut()
+            .insert((key, self.position().offset()), entry);
+    }
+}
+
+impl<S> Recoverable for MemoStream<S>
+where
+    S: Recoverable,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    fn record(&self, error: ParseError<Self::Item, Self::Pos>) -> Self {
+        self.wrap(self.0.record(error))
+    }
+}
+
+impl<S> Len for MemoStream<S>
+where
+    S: Stream + Len,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<S> Indentable for MemoStream<S>
+where
+    S: Indentable,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    fn column(&self) -> usize {
+        self.0.column()
+    }
+}
+
+impl<S> Slice for MemoStream<S>
+where
+    S: Slice,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    type Slice = S::Slice;
+
+    fn slice(&self, end: &Self) -> Self::Slice {
+        self.0.slice(&end.0)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Stream adding data, like a state or recorded errors, on top of a memoized stream.
+pub(crate) trait Layer: Stream {
+    type Inner: Stream<Item = Self::Item, Pos = Self::Pos>;
+    type Data: Clone + 'static;
+
+    fn split(&self) -> (&Self::Inner, &Self::Data);
+
+    fn join(s: Self::Inner, data: Self::Data) -> Self;
+
+    fn same(&self, data: &Self::Data) -> bool;
+}
+
+// The inner stream memoizes the data of the layer when the parse starts and ends. The error of
+// a reject is kept as the hint of a success so that the end data is kept as well.
+type Layered<A, D> = (D, Result<(A, D), D>);
+
+pub(crate) fn recall<A, L>(layer: &L, key: usize) -> Option<Response<A, L>>
+where
+    A: Clone + 'static,
+    L: Layer,
+    L::Item: Clone,
+    L::Pos: Clone,
+{
+    match layer.split().0.recall::<Layered<A, L::Data>>(key)? {
+        Success((start, result), s, c, h) if layer.same(&start) => match (result, h) {
+            (Ok((a, data)), h) => Some(Success(a, L::join(s, data), c, h)),
+            (Err(data), Some(e)) => Some(Reject(L::join(s, data), c, e)),
+            (Err(_), None) => None,
+        },
+        _ => None,
+    }
+}
+
+pub(crate) fn memoize<A, L>(layer: &L, key: usize, response: &Response<A, L>)
+where
+    A: Clone + 'static,
+    L: Layer,
+    L::Item: Clone,
+    L::Pos: Clone,
+{
+    let (inner, start) = layer.split();
+
+    let layered: Response<Layered<A, L::Data>, L::Inner> = match response {
+        Success(a, l, c, h) => {
+            let (s, data) = l.split();
+            let result = Ok((a.clone(), data.clone()));
+            Success((start.clone(), result), s.clone(), *c, h.clone())
+        }
+        Reject(l, c, e) => {
+            let (s, data) = l.split();
+            let result = Err(data.clone());
+            Success((start.clone(), result), s.clone(), *c, Some(e.clone()))
+        }
+    };
+
+    inner.memoize(key, &layered);
+}
@@ -15,9 +15,16 @@
 */
 
 pub mod array_stream;
+pub mod bit_stream;
+pub mod byte_stream;
 pub mod char_stream;
 pub mod end_line;
+pub mod frame_stream;
 pub mod iterator_stream;
+pub mod memo_stream;
 pub mod parser_stream;
+pub mod partial_stream;
 pub mod position;
+pub mod recovery_stream;
 pub mod specs;
+pub mod stateful_stream;
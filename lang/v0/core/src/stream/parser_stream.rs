@@ -14,6 +14,7 @@
    limitations under the License.
 */
 
+use std::cell::Cell;
 use std::marker::PhantomData;
 
 use crate::parser::response::Response::Reject;
@@ -21,9 +22,19 @@ use crate::parser::response::Response::Success;
 use crate::parser::specs::Combine;
 use crate::parser::specs::Parse;
 use crate::stream::position::Position;
+use crate::stream::specs::Indentable;
+use crate::stream::specs::Located;
+use crate::stream::specs::Stateless;
 use crate::stream::specs::Stream;
 
-pub struct ParserStream<'a, P, A, S, L>(&'a P, S, PhantomData<A>, PhantomData<L>)
+pub struct ParserStream<'a, P, A, S, L>(
+    &'a P,
+    S,
+    // Whether the parser waits for more items here, known once it has been run.
+    Cell<Option<bool>>,
+    PhantomData<A>,
+    PhantomData<L>,
+)
 where
     P: Combine<A> + Parse<A, S>,
     S: Stream<Pos = L>,
@@ -37,7 +48,7 @@ where
 {
     #[inline]
     pub fn new(p: &'a P, s: S) -> Self {
-        ParserStream(p, s, PhantomData, PhantomData)
+        ParserStream(p, s, Cell::new(None), PhantomData, PhantomData)
     }
 }
 
@@ -48,7 +59,13 @@ where
     L: Position,
 {
     fn clone(&self) -> Self {
-        ParserStream(self.0, self.1.clone(), PhantomData, PhantomData)
+        ParserStream(
+            self.0,
+            self.1.clone(),
+            self.2.clone(),
+            PhantomData,
+            PhantomData,
+        )
     }
 }
 
@@ -67,8 +84,47 @@ where
 
     fn next(&self) -> (Option<Self::Item>, Self) {
         match self.0.parse(self.1.clone()) {
-            Success(a, s, _) => (Some(a), ParserStream::new(self.0, s)),
-            Reject(_, _) => (None, ParserStream::new(self.0, self.1.clone())),
+            Success(a, s, _, _) => {
+                self.2.set(Some(false));
+                (Some(a), ParserStream::new(self.0, s))
+            }
+            Reject(_, _, e) => {
+                self.2.set(Some(e.incomplete));
+                (None, self.clone())
+            }
+        }
+    }
+
+    fn is_partial(&self) -> bool {
+        match self.2.get() {
+            Some(partial) => partial,
+            None => {
+                self.next();
+                self.2.get() == Some(true)
+            }
+        }
+    }
+}
+
+impl<P, A, S, L> Stateless for ParserStream<'_, P, A, S, L>
+where
+    P: Combine<A> + Parse<A, S>,
+    S: Stateless<Pos = L>,
+    L: Position,
+{
+}
+
+impl<P, A, S, L> Indentable for ParserStream<'_, P, A, S, L>
+where
+    P: Combine<A> + Parse<A, S>,
+    A: Located,
+    S: Stream<Pos = L>,
+    L: Position,
+{
+    fn column(&self) -> usize {
+        match self.next().0 {
+            Some(a) => a.column(),
+            None => self.position().char_number(),
         }
     }
 }
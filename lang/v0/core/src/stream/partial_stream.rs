@@ -0,0 +1,164 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use crate::parser::error::ParseError;
+use crate::parser::response::Response;
+use crate::stream::memo_stream;
+use crate::stream::memo_stream::Layer;
+use crate::stream::specs::Indentable;
+use crate::stream::specs::Len;
+use crate::stream::specs::Recoverable;
+use crate::stream::specs::Slice;
+use crate::stream::specs::Stateless;
+use crate::stream::specs::Stream;
+
+/// Stream whose end is only the end of the data received so far. Parsers reaching it
+/// reject with an incomplete error until the stream is closed.
+#[derive(Clone)]
+pub struct PartialStream<S>(S, bool)
+where
+    S: Stream;
+
+impl<S> PartialStream<S>
+where
+    S: Stream,
+{
+    pub fn new(s: S) -> Self {
+        PartialStream(s, true)
+    }
+
+    pub fn close(self) -> Self {
+        PartialStream(self.0, false)
+    }
+
+    pub fn stream(self) -> S {
+        self.0
+    }
+}
+
+impl<S> Stream for PartialStream<S>
+where
+    S: Stream,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    type Item = S::Item;
+    type Pos = S::Pos;
+
+    fn position(&self) -> Self::Pos {
+        self.0.position()
+    }
+
+    fn next(&self) -> (Option<Self::Item>, Self) {
+        let (item, s) = self.0.next();
+
+        (item, PartialStream(s, self.1))
+    }
+
+    fn is_partial(&self) -> bool {
+        self.1 || self.0.is_partial()
+    }
+
+    fn recall<A>(&self, key: usize) -> Option<Response<A, Self>>
+    where
+        A: Clone + 'static,
+    {
+        memo_stream::recall(self, key)
+    }
+
+    fn memoize<A>(&self, key: usize, response: &Response<A, Self>)
+    where
+        A: Clone + 'static,
+    {
+        memo_stream::memoize(self, key, response)
+    }
+}
+
+impl<S> Layer for PartialStream<S>
+where
+    S: Stream,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    type Inner = S;
+    type Data = bool;
+
+    fn split(&self) -> (&Self::Inner, &Self::Data) {
+        (&self.0, &self.1)
+    }
+
+    fn join(s: Self::Inner, data: Self::Data) -> Self {
+        PartialStream(s, data)
+    }
+
+    fn same(&self, data: &Self::Data) -> bool {
+        self.1 == *data
+    }
+}
+
+impl<S> Stateless for PartialStream<S>
+where
+    S: Stateless,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+}
+
+impl<S> Recoverable for PartialStream<S>
+where
+    S: Recoverable,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    fn record(&self, error: ParseError<Self::Item, Self::Pos>) -> Self {
+        PartialStream(self.0.record(error), self.1)
+    }
+}
+
+impl<S> Len for PartialStream<S>
+where
+    S: Stream + Len,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<S> Indentable for PartialStream<S>
+where
+    S: Indentable,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    fn column(&self) -> usize {
+        self.0.column()
+    }
+}
+
+impl<S> Slice for PartialStream<S>
+where
+    S: Slice,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    type Slice = S::Slice;
+
+    fn slice(&self, end: &Self) -> Self::Slice {
+        self.0.slice(&end.0)
+    }
+}
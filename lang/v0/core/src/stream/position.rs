@@ -14,13 +14,22 @@
    limitations under the License.
 */
 
-pub trait Position {
+pub trait Position: Sized {
     fn new() -> Self;
 
     fn step(&self, newline: bool) -> Self;
 
+    fn step_char(&self, c: char) -> Self {
+        self.step(c == '\n')
+    }
+
     fn offset(&self) -> usize;
 
+    /// Positions which do not track bytes count items, i.e. bytes of a `ByteStream`.
+    fn byte_offset(&self) -> usize {
+        self.offset()
+    }
+
     fn char_number(&self) -> usize {
         self.offset()
     }
@@ -64,10 +73,48 @@ impl Position for (usize, usize, usize) {
     }
 
     fn char_number(&self) -> usize {
-        self.1
+        self.2
     }
 
     fn line_number(&self) -> usize {
+        self.1
+    }
+}
+
+/// Position in a `str` given by the char index, the line, the column and the byte offset.
+/// The byte offset follows the UTF-8 width of each character.
+impl Position for (usize, usize, usize, usize) {
+    fn new() -> Self {
+        (0, 1, 0, 0)
+    }
+
+    #[inline]
+    fn step(&self, newline: bool) -> Self {
+        self.step_char(if newline { '\n' } else { ' ' })
+    }
+
+    #[inline]
+    fn step_char(&self, c: char) -> Self {
+        if c == '\n' {
+            (self.0 + 1, self.1 + 1, 0, self.3 + 1)
+        } else {
+            (self.0 + 1, self.1, self.2 + 1, self.3 + c.len_utf8())
+        }
+    }
+
+    fn offset(&self) -> usize {
+        self.0
+    }
+
+    fn byte_offset(&self) -> usize {
+        self.3
+    }
+
+    fn char_number(&self) -> usize {
         self.2
     }
+
+    fn line_number(&self) -> usize {
+        self.1
+    }
 }
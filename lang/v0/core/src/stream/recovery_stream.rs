@@ -0,0 +1,194 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+use std::rc::Rc;
+
+use crate::parser::error::ParseError;
+use crate::parser::response::Response;
+use crate::stream::memo_stream;
+use crate::stream::memo_stream::Layer;
+use crate::stream::specs::Indentable;
+use crate::stream::specs::Len;
+use crate::stream::specs::Recoverable;
+use crate::stream::specs::Slice;
+use crate::stream::specs::Stream;
+
+pub struct RecoveryStream<S>(S, Option<Rc<Recorded<S::Item, S::Pos>>>)
+where
+    S: Stream;
+
+/// Recorded errors shared between streams, the last recorded one first.
+pub(crate) struct Recorded<I, P>(ParseError<I, P>, Option<Rc<Recorded<I, P>>>);
+
+impl<I, P> Drop for Recorded<I, P> {
+    fn drop(&mut self) {
+        let mut next = self.1.take();
+
+        while let Some(Ok(mut recorded)) = next.map(Rc::try_unwrap) {
+            next = recorded.1.take();
+        }
+    }
+}
+
+impl<S> RecoveryStream<S>
+where
+    S: Stream,
+{
+    pub fn new(s: S) -> Self {
+        RecoveryStream(s, None)
+    }
+}
+
+impl<S> RecoveryStream<S>
+where
+    S: Stream,
+    S::Item: Clone,
+    S::Pos: Clone,
+{
+    pub fn errors(&self) -> Vec<ParseError<S::Item, S::Pos>> {
+        let mut errors = Vec::new();
+        let mut next = &self.1;
+
+        while let Some(recorded) = next {
+            errors.push(recorded.0.clone());
+            next = &recorded.1;
+        }
+
+        errors.reverse();
+        errors
+    }
+}
+
+impl<S> Clone for RecoveryStream<S>
+where
+    S: Stream,
+{
+    fn clone(&self) -> Self {
+        RecoveryStream(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<S> Stream for RecoveryStream<S>
+where
+    S: Stream,
+    S::Item: Clone + 'static,
+    S::Pos: Clone + 'static,
+{
+    type Item = S::Item;
+    type Pos = S::Pos;
+
+    fn position(&self) -> Self::Pos {
+        self.0.position()
+    }
+
+    fn next(&self) -> (Option<Self::Item>, Self) {
+        let (item, s) = self.0.next();
+
+        (item, RecoveryStream(s, self.1.clone()))
+    }
+
+    fn is_partial(&self) -> bool {
+        self.0.is_partial()
+    }
+
+    fn recall<A>(&self, key: usize) -> Option<Response<A, Self>>
+    where
+        A: Clone + 'static,
+    {
+        memo_stream::recall(self, key)
+    }
+
+    fn memoize<A>(&self, key: usize, response: &Response<A, Self>)
+    where
+        A: Clone + 'static,
+    {
+        memo_stream::memoize(self, key, response)
+    }
+}
+
+impl<S> Layer for RecoveryStream<S>
+where
+    S: Stream,
+    S::Item: Clone + 'static,
+    S::Pos: Clone + 'static,
+{
+    type Inner = S;
+    type Data = Option<Rc<Recorded<S::Item, S::Pos>>>;
+
+    fn split(&self) -> (&Self::Inner, &Self::Data) {
+        (&self.0, &self.1)
+    }
+
+    fn join(s: Self::Inner, data: Self::Data) -> Self {
+        RecoveryStream(s, data)
+    }
+
+    fn same(&self, data: &Self::Data) -> bool {
+        match (&self.1, data) {
+            (Some(l), Some(r)) => Rc::ptr_eq(l, r),
+            (l, r) => l.is_none() && r.is_none(),
+        }
+    }
+}
+
+impl<S> Recoverable for RecoveryStream<S>
+where
+    S: Stream,
+    S::Item: Clone + 'static,
+    S::Pos: Clone + 'static,
+{
+    fn record(&self, error: ParseError<Self::Item, Self::Pos>) -> Self {
+        RecoveryStream(
+            self.0.clone(),
+            Some(Rc::new(Recorded(error, self.1.clone()))),
+        )
+    }
+}
+
+impl<S> Len for RecoveryStream<S>
+where
+    S: Stream + Len,
+    S::Item: Clone + 'static,
+    S::Pos: Clone + 'static,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<S> Indentable for RecoveryStream<S>
+where
+    S: Indentable,
+    S::Item: Clone + 'static,
+    S::Pos: Clone + 'static,
+{
+    fn column(&self) -> usize {
+        self.0.column()
+    }
+}
+
+impl<S> Slice for RecoveryStream<S>
+where
+    S: Slice,
+    S::Item: Clone + 'static,
+    S::Pos: Clone + 'static,
+{
+    type Slice = S::Slice;
+
+    fn slice(&self, end: &Self) -> Self::Slice {
+        self.0.slice(&end.0)
+    }
+}
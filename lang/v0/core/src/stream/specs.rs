@@ -14,6 +14,8 @@
    limitations under the License.
 */
 
+use crate::parser::error::ParseError;
+use crate::parser::response::Response;
 use crate::stream::position::Position;
 
 pub trait Stream: Clone {
@@ -23,6 +25,23 @@ pub trait Stream: Clone {
     fn position(&self) -> Self::Pos;
 
     fn next(&self) -> (Option<Self::Item>, Self);
+
+    fn is_partial(&self) -> bool {
+        false
+    }
+
+    fn recall<A>(&self, _key: usize) -> Option<Response<A, Self>>
+    where
+        A: Clone + 'static,
+    {
+        None
+    }
+
+    fn memoize<A>(&self, _key: usize, _response: &Response<A, Self>)
+    where
+        A: Clone + 'static,
+    {
+    }
 }
 
 pub trait Len {
@@ -32,3 +51,32 @@ pub trait Len {
         self.len() == 0
     }
 }
+
+pub trait Recoverable: Stream {
+    fn record(&self, error: ParseError<Self::Item, Self::Pos>) -> Self;
+}
+
+pub trait Slice: Stream {
+    type Slice;
+
+    fn slice(&self, end: &Self) -> Self::Slice;
+}
+
+/// Stream whose parsing only depends on its position. Memoization applies to such streams.
+pub trait Stateless: Stream {}
+
+pub trait Stateful: Stream {
+    type State;
+
+    fn state(&self) -> &Self::State;
+
+    fn with_state(&self, state: Self::State) -> Self;
+}
+
+pub trait Indentable: Stream {
+    fn column(&self) -> usize;
+}
+
+pub trait Located {
+    fn column(&self) -> usize;
+}
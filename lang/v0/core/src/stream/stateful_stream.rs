@@ -0,0 +1,179 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+use std::rc::Rc;
+
+use crate::parser::error::ParseError;
+use crate::parser::response::Response;
+use crate::stream::memo_stream;
+use crate::stream::memo_stream::Layer;
+use crate::stream::specs::Indentable;
+use crate::stream::specs::Len;
+use crate::stream::specs::Recoverable;
+use crate::stream::specs::Slice;
+use crate::stream::specs::Stateful;
+use crate::stream::specs::Stream;
+
+pub struct StatefulStream<S, U>(S, Rc<U>)
+where
+    S: Stream;
+
+impl<S, U> StatefulStream<S, U>
+where
+    S: Stream,
+{
+    pub fn new(s: S, state: U) -> Self {
+        StatefulStream(s, Rc::new(state))
+    }
+}
+
+impl<S, U> Clone for StatefulStream<S, U>
+where
+    S: Stream,
+{
+    fn clone(&self) -> Self {
+        StatefulStream(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<S, U> Stream for StatefulStream<S, U>
+where
+    S: Stream,
+    S::Item: Clone,
+    S::Pos: Clone,
+    U: 'static,
+{
+    type Item = S::Item;
+    type Pos = S::Pos;
+
+    fn position(&self) -> Self::Pos {
+        self.0.position()
+    }
+
+    fn next(&self) -> (Option<Self::Item>, Self) {
+        let (item, s) = self.0.next();
+
+        (item, StatefulStream(s, self.1.clone()))
+    }
+
+    fn is_partial(&self) -> bool {
+        self.0.is_partial()
+    }
+
+    fn recall<A>(&self, key: usize) -> Option<Response<A, Self>>
+    where
+        A: Clone + 'static,
+    {
+        memo_stream::recall(self, key)
+    }
+
+    fn memoize<A>(&self, key: usize, response: &Response<A, Self>)
+    where
+        A: Clone + 'static,
+    {
+        memo_stream::memoize(self, key, response)
+    }
+}
+
+impl<S, U> Layer for StatefulStream<S, U>
+where
+    S: Stream,
+    S::Item: Clone,
+    S::Pos: Clone,
+    U: 'static,
+{
+    type Inner = S;
+    type Data = Rc<U>;
+
+    fn split(&self) -> (&Self::Inner, &Self::Data) {
+        (&self.0, &self.1)
+    }
+
+    fn join(s: Self::Inner, data: Self::Data) -> Self {
+        StatefulStream(s, data)
+    }
+
+    fn same(&self, data: &Self::Data) -> bool {
+        Rc::ptr_eq(&self.1, data)
+    }
+}
+
+impl<S, U> Stateful for StatefulStream<S, U>
+where
+    S: Stream,
+    S::Item: Clone,
+    S::Pos: Clone,
+    U: 'static,
+{
+    type State = U;
+
+    fn state(&self) -> &Self::State {
+        &self.1
+    }
+
+    fn with_state(&self, state: Self::State) -> Self {
+        StatefulStream(self.0.clone(), Rc::new(state))
+    }
+}
+
+impl<S, U> Recoverable for StatefulStream<S, U>
+where
+    S: Recoverable,
+    S::Item: Clone,
+    S::Pos: Clone,
+    U: 'static,
+{
+    fn record(&self, error: ParseError<Self::Item, Self::Pos>) -> Self {
+        StatefulStream(self.0.record(error), self.1.clone())
+    }
+}
+
+impl<S, U> Len for StatefulStream<S, U>
+where
+    S: Stream + Len,
+    S::Item: Clone,
+    S::Pos: Clone,
+    U: 'static,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<S, U> Indentable for StatefulStream<S, U>
+where
+    S: Indentable,
+    S::Item: Clone,
+    S::Pos: Clone,
+    U: 'static,
+{
+    fn column(&self) -> usize {
+        self.0.column()
+    }
+}
+
+impl<S, U> Slice for StatefulStream<S, U>
+where
+    S: Slice,
+    S::Item: Clone,
+    S::Pos: Clone,
+    U: 'static,
+{
+    type Slice = S::Slice;
+
+    fn slice(&self, end: &Self) -> Self::Slice {
+        self.0.slice(&end.0)
+    }
+}
@@ -14,5 +14,14 @@
    limitations under the License.
 */
 
+#![allow(
+    clippy::almost_complete_range,
+    clippy::bool_assert_comparison,
+    clippy::cmp_owned,
+    clippy::partialeq_to_none,
+    clippy::redundant_closure,
+    clippy::unit_cmp
+)]
+
 pub mod parser;
 pub mod stream;
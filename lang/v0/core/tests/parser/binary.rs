@@ -0,0 +1,262 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_binary {
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::binary::{
+        f32_be, f64_le, i8, i16_be, i32_le, length_prefixed, sleb128, take, u8, u16_be, u16_le,
+        u32_be, u32_le, u64_be, uleb128,
+    };
+    use celma_v0_core::parser::error::{Expected, ParseError};
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::response::Response::{Reject, Success};
+    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::stream::array_stream::ArrayStream;
+    use celma_v0_core::stream::byte_stream::ByteStream;
+
+    #[test]
+    fn it_parse_a_byte() {
+        let response = u8().parse(ByteStream::new(&[0xff]));
+
+        assert_eq!(response.fold(|v, _, _| v == 0xff, |_, _| false), true);
+    }
+
+    #[test]
+    fn it_parse_a_signed_byte() {
+        let response = i8().parse(ByteStream::new(&[0xff]));
+
+        assert_eq!(response.fold(|v, _, _| v == -1, |_, _| false), true);
+    }
+
+    #[test]
+    fn it_parse_big_and_little_endian_u16() {
+        let response = u16_be().and(u16_le()).parse(ByteStream::new(&[1, 2, 1, 2]));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == (0x0102, 0x0201), |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_parse_big_and_little_endian_u32() {
+        let response = u32_be()
+            .and(u32_le())
+            .parse(ByteStream::new(&[1, 2, 3, 4, 1, 2, 3, 4]));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == (0x01020304, 0x04030201), |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_parse_u64() {
+        let response = u64_be().parse(ByteStream::new(&[0, 0, 0, 0, 0, 0, 1, 0]));
+
+        assert_eq!(response.fold(|v, _, _| v == 256, |_, _| false), true);
+    }
+
+    #[test]
+    fn it_parse_signed_integers() {
+        let response = i16_be()
+            .and(i32_le())
+            .parse(ByteStream::new(&[0xff, 0xfe, 0xfe, 0xff, 0xff, 0xff]));
+
+        assert_eq!(response.fold(|v, _, _| v == (-2, -2), |_, _| false), true);
+    }
+
+    #[test]
+    fn it_parse_floats() {
+        let mut data = 1.5f32.to_be_bytes().to_vec();
+        data.extend_from_slice(&(-0.25f64).to_le_bytes());
+        let response = f32_be().and(f64_le()).parse(ByteStream::new(&data));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == (1.5, -0.25), |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_parse_numbers_from_an_array_stream() {
+        let response = u16_be().parse(ArrayStream::new(&[1u8, 2]));
+
+        assert_eq!(response.fold(|v, _, _| v == 0x0102, |_, _| false), true);
+    }
+
+    #[test]
+    fn it_cannot_parse_a_truncated_number() {
+        let response = u32_be().parse(ByteStream::new(&[1, 2]));
+
+        match response {
+            Reject(_, false, e) => assert_eq!(
+                e,
+                ParseError::new(2, None, vec![Expected::Label("4 bytes".to_string())])
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_unsigned_varints() {
+        let response = uleb128()
+            .rep()
+            .parse(ByteStream::new(&[0x00, 0x7f, 0xe5, 0x8e, 0x26]));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == vec![0, 127, 624485], |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_parse_signed_varints() {
+        let response = sleb128()
+            .rep()
+            .parse(ByteStream::new(&[0x02, 0x7f, 0xc0, 0xbb, 0x78]));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == vec![2, -1, -123456], |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_parse_extreme_signed_varint() {
+        let data = [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f];
+        let response = sleb128().parse(ByteStream::new(&data));
+
+        assert_eq!(response.fold(|v, _, _| v == i64::MIN, |_, _| false), true);
+    }
+
+    #[test]
+    fn it_parse_extreme_unsigned_varint() {
+        let data = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+        let response = uleb128().parse(ByteStream::new(&data));
+
+        assert_eq!(response.fold(|v, _, _| v == u64::MAX, |_, _| false), true);
+    }
+
+    #[test]
+    fn it_cannot_parse_an_overflowing_unsigned_varint() {
+        let data = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f];
+        let response = uleb128().parse(ByteStream::new(&data));
+
+        match response {
+            Reject(_, false, e) => assert_eq!((e.position, e.found), (9, Some(0x7f))),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_cannot_parse_an_overflowing_signed_varint() {
+        let data = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+        let response = sleb128().parse(ByteStream::new(&data));
+
+        assert_eq!(response.fold(|_, _, _| false, |_, c| !c), true);
+    }
+
+    #[test]
+    fn it_cannot_parse_an_unterminated_varint() {
+        let response = uleb128().parse(ByteStream::new(&[0x80, 0x80]));
+
+        assert_eq!(response.fold(|_, _, _| false, |_, c| !c), true);
+    }
+
+    #[test]
+    fn it_take_a_slice() {
+        let response = take(2).and(take(1)).parse(ByteStream::new(&[1, 2, 3]));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == (&[1, 2][..], &[3][..]), |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_cannot_take_a_slice() {
+        let response = take(4).parse(ByteStream::new(&[1, 2, 3]));
+
+        match response {
+            Reject(_, false, e) => assert_eq!(
+                e,
+                ParseError::new(3, None, vec![Expected::Label("4 bytes".to_string())])
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_length_prefixed_body() {
+        let response = length_prefixed(u8(), take(3))
+            .and(u8())
+            .parse(ByteStream::new(&[3, b'a', b'b', b'c', 9]));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == (&b"abc"[..], 9), |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_skip_the_rest_of_a_length_prefixed_frame() {
+        let response = length_prefixed(u16_be(), u8())
+            .and(u8())
+            .parse(ByteStream::new(&[0, 3, 1, 2, 3, 4]));
+
+        assert_eq!(response.fold(|v, _, _| v == (1, 4), |_, _| false), true);
+    }
+
+    #[test]
+    fn it_cannot_parse_beyond_a_length_prefixed_frame() {
+        let response = length_prefixed(u8(), u16_be()).parse(ByteStream::new(&[1, 2, 3]));
+
+        match response {
+            Reject(_, true, e) => assert_eq!(
+                e,
+                ParseError::new(2, None, vec![Expected::Label("2 bytes".to_string())])
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_cannot_parse_a_truncated_length_prefixed_frame() {
+        let response = length_prefixed(u8(), take(3)).parse(ByteStream::new(&[3, 1]));
+
+        match response {
+            Reject(_, true, e) => assert_eq!(
+                e,
+                ParseError::new(2, None, vec![Expected::Label("3 bytes".to_string())])
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_length_prefixed_records() {
+        let response = length_prefixed(uleb128(), take(2).rep())
+            .rep()
+            .parse(ByteStream::new(&[2, 1, 2, 4, 3, 4, 5, 6]));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, vec![vec![&[1, 2][..]], vec![&[3, 4], &[5, 6]]]),
+            _ => panic!(),
+        };
+    }
+}
@@ -0,0 +1,168 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_bits {
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::binary::{length_prefixed, take, u8};
+    use celma_v0_core::parser::bits::{align, align_to, bits, flag};
+    use celma_v0_core::parser::core::any;
+    use celma_v0_core::parser::error::{Expected, ParseError};
+    use celma_v0_core::parser::map::MapOperation;
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::response::Response::{Reject, Success};
+    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::stream::bit_stream::BitStream;
+    use celma_v0_core::stream::byte_stream::ByteStream;
+    use celma_v0_core::stream::parser_stream::ParserStream;
+
+    #[test]
+    fn it_parse_flags() {
+        let response = flag().and(flag()).parse(BitStream::new(&[0b1000_0000]));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == (true, false), |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_parse_bit_fields() {
+        let response = bits(3)
+            .and(bits(5))
+            .and(bits(4))
+            .parse(BitStream::new(&[0b101_10011, 0b1110_0000]));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == ((0b101, 0b10011), 0b1110), |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_parse_bit_fields_across_bytes() {
+        let response = bits(4).and(bits(12)).parse(BitStream::new(&[0xab, 0xcd]));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == (0xa, 0xbcd), |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_parse_64_bits() {
+        let response = bits(64).parse(BitStream::new(&[0xff; 8]));
+
+        assert_eq!(response.fold(|v, _, _| v == u64::MAX, |_, _| false), true);
+    }
+
+    #[test]
+    fn it_cannot_parse_missing_bits() {
+        let response = bits(4).and(bits(8)).parse(BitStream::new(&[0xff]));
+
+        match response {
+            Reject(_, true, e) => assert_eq!(
+                e,
+                ParseError::new(8, None, vec![Expected::Label("8 bits".to_string())])
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_align_to_the_next_byte() {
+        let response = flag()
+            .and_left(align())
+            .and(bits(8))
+            .parse(BitStream::new(&[0b1000_0000, 0x2a]));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == (true, 0x2a), |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_does_not_consume_when_aligned() {
+        let response = align().parse(BitStream::new(&[0xff]));
+
+        assert_eq!(
+            response.fold(|_, s, c| !c && s.bit_offset() == 0, |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_align_to_a_nibble() {
+        let response = flag()
+            .and_left(align_to(4))
+            .and(bits(4))
+            .parse(BitStream::new(&[0b0000_1010]));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == (false, 0b1010), |_, _| false),
+            true
+        );
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    struct Field(bool, u64);
+
+    #[test]
+    fn it_parse_bit_fields_in_a_pipeline() {
+        let field = flag().and(bits(3)).map(|(f, v)| Field(f, v));
+        let stream = ParserStream::new(&field, BitStream::new(&[0b1010_0001]));
+        let response = any().rep().parse(stream);
+
+        assert_eq!(
+            response.fold(
+                |v, _, _| v == vec![Field(true, 0b010), Field(false, 0b001)],
+                |_, _| false
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn it_parse_bit_fields_of_byte_frames_in_a_pipeline() {
+        let frame = length_prefixed(u8(), take(1)).map(|frame: &[u8]| {
+            flag()
+                .and(bits(7))
+                .parse(BitStream::new(frame))
+                .fold(|v, _, _| Some(v), |_, _| None)
+        });
+        let stream = ParserStream::new(&frame, ByteStream::new(&[1, 0b1100_0101, 1, 0b0011_1010]));
+        let response = any().rep().parse(stream);
+
+        assert_eq!(
+            response.fold(
+                |v, _, _| v == vec![Some((true, 0b100_0101)), Some((false, 0b011_1010))],
+                |_, _| false
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn it_repeat_bit_fields() {
+        let response = bits(2).rep().parse(BitStream::new(&[0b00_01_10_11]));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, vec![0, 1, 2, 3]),
+            _ => panic!(),
+        };
+    }
+}
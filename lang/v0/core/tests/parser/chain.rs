@@ -0,0 +1,77 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_chain {
+    use celma_v0_core::parser::chain::{ChainOperation, chainl1, chainr1};
+    use celma_v0_core::parser::char::{a_char, digit};
+    use celma_v0_core::parser::map::MapOperation;
+    use celma_v0_core::parser::specs::parse_str;
+
+    #[test]
+    fn it_parse_one_term() {
+        let response = parse_str(
+            &chainl1(
+                digit().map(|c| c.to_digit(10).unwrap()),
+                a_char('-').map(|_| |l: u32, r: u32| l - r),
+            ),
+            "7",
+        );
+
+        assert_eq!(response, Ok(7));
+    }
+
+    #[test]
+    fn it_folds_to_the_left() {
+        let response = parse_str(
+            &digit()
+                .map(|c| c.to_digit(10).unwrap() as i32)
+                .chainl1(a_char('-').map(|_| |l: i32, r: i32| l - r)),
+            "8-2-1",
+        );
+
+        assert_eq!(response, Ok(5));
+    }
+
+    #[test]
+    fn it_folds_to_the_right() {
+        let response = parse_str(
+            &chainr1(
+                digit().map(|c| c.to_digit(10).unwrap() as i32),
+                a_char('-').map(|_| |l: i32, r: i32| l - r),
+            ),
+            "8-2-1",
+        );
+
+        assert_eq!(response, Ok(7));
+    }
+
+    #[test]
+    fn it_rejects_a_missing_term() {
+        let response = parse_str(
+            &chainl1(
+                digit().map(|c| c.to_digit(10).unwrap()),
+                a_char('+').map(|_| |l: u32, r: u32| l + r),
+            ),
+            "1+",
+        );
+
+        assert_eq!(
+            response.map_err(|e| e.to_string()),
+            Err("expected '0'..'9', found end of stream".to_string())
+        );
+    }
+}
@@ -20,8 +20,16 @@ mod tests_char {
     use celma_v0_core::parser::char::alpha;
     use celma_v0_core::parser::char::alpha_lower;
     use celma_v0_core::parser::char::alpha_upper;
+    use celma_v0_core::parser::char::category;
     use celma_v0_core::parser::char::digit;
     use celma_v0_core::parser::char::not_char;
+    use celma_v0_core::parser::char::unicode_alpha;
+    use celma_v0_core::parser::char::unicode_alphanumeric;
+    use celma_v0_core::parser::char::unicode_whitespace;
+    use celma_v0_core::parser::char::xid_continue;
+    use celma_v0_core::parser::char::xid_start;
+    use celma_v0_core::parser::error::{Expected, ParseError};
+    use celma_v0_core::parser::response::Response::Reject;
     use celma_v0_core::parser::specs::Parse;
     use celma_v0_core::stream::char_stream::CharStream;
 
@@ -115,6 +123,102 @@ mod tests_char {
 
         assert_eq!(response.fold(|_, _, _| false, |_, _| true), true);
     }
+
+    #[test]
+    fn it_can_parse_a_unicode_alpha() {
+        let response = unicode_alpha().parse(CharStream::new("é"));
+
+        assert_eq!(response.fold(|v, _, _| v == 'é', |_, _| false), true);
+    }
+
+    #[test]
+    fn it_cannot_parse_a_unicode_alpha() {
+        let response = unicode_alpha().parse(CharStream::new("٣"));
+
+        assert_eq!(response.fold(|_, _, _| false, |_, _| true), true);
+    }
+
+    #[test]
+    fn it_can_parse_a_unicode_alphanumeric() {
+        let response = unicode_alphanumeric().parse(CharStream::new("٣"));
+
+        assert_eq!(response.fold(|v, _, _| v == '٣', |_, _| false), true);
+    }
+
+    #[test]
+    fn it_can_parse_a_unicode_whitespace() {
+        let response = unicode_whitespace().parse(CharStream::new("\u{3000}"));
+
+        assert_eq!(response.fold(|v, _, _| v == '\u{3000}', |_, _| false), true);
+    }
+
+    #[test]
+    fn it_can_parse_an_identifier_start() {
+        let response = xid_start().parse(CharStream::new("变"));
+
+        assert_eq!(response.fold(|v, _, _| v == '变', |_, _| false), true);
+    }
+
+    #[test]
+    fn it_cannot_parse_an_identifier_start() {
+        let response = xid_start().parse(CharStream::new("1"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e,
+                ParseError::new(
+                    (0, 1, 0, 0),
+                    Some('1'),
+                    vec![Expected::Label("identifier start".to_string())]
+                )
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_can_parse_an_identifier_continue() {
+        let response = xid_continue().parse(CharStream::new("1"));
+
+        assert_eq!(response.fold(|v, _, _| v == '1', |_, _| false), true);
+    }
+
+    #[test]
+    fn it_can_parse_an_uppercase_letter_category() {
+        let response = category("Lu").parse(CharStream::new("É"));
+
+        assert_eq!(response.fold(|v, _, _| v == 'É', |_, _| false), true);
+    }
+
+    #[test]
+    fn it_cannot_parse_an_uppercase_letter_category() {
+        let response = category("Lu").parse(CharStream::new("é"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e,
+                ParseError::new(
+                    (0, 1, 0, 0),
+                    Some('é'),
+                    vec![Expected::Label("category Lu".to_string())]
+                )
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_can_parse_a_major_category() {
+        let response = category("L").parse(CharStream::new("é"));
+
+        assert_eq!(response.fold(|v, _, _| v == 'é', |_, _| false), true);
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown general category Xx")]
+    fn it_refuses_an_unknown_category() {
+        category("Xx").parse(CharStream::new("é"));
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
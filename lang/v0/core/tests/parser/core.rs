@@ -55,7 +55,7 @@ mod tests_core {
     fn it_checks_eos() {
         let response = eos().parse(CharStream::new(""));
 
-        assert_eq!(response.fold(|_, _, _| true, |_, _| false), true);
+        assert_eq!(response.fold(|v, _, _| v == (), |_, _| false), true);
     }
 
     #[test]
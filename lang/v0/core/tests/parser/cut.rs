@@ -0,0 +1,96 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_cut {
+    use celma_v0_core::parser::a_try::a_try;
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::a_char;
+    use celma_v0_core::parser::cut::{CutOperation, cut};
+    use celma_v0_core::parser::error::{Expected, ParseError};
+    use celma_v0_core::parser::option::OptionalOperation;
+    use celma_v0_core::parser::or::OrOperation;
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::response::Response::Reject;
+    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::stream::char_stream::CharStream;
+
+    #[test]
+    fn it_parse_a_cut_parser() {
+        let response = cut(a_char('a')).parse(CharStream::new("a"));
+
+        assert_eq!(response.fold(|v, _, _| v == 'a', |_, _| false), true);
+    }
+
+    #[test]
+    fn it_does_not_try_the_next_alternative() {
+        let response = a_char('a')
+            .cut()
+            .or(a_char('b'))
+            .parse(CharStream::new("b"));
+
+        match response {
+            Reject(_, c, e) => {
+                assert_eq!(c, false);
+                assert_eq!(
+                    e,
+                    ParseError::new((0, 1, 0, 0), Some('b'), vec![Expected::Item('a')]).commit()
+                );
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_keeps_the_error_local() {
+        let response = a_char('a')
+            .opt()
+            .and_right(cut(a_char('b')))
+            .or(a_char('d'))
+            .parse(CharStream::new("c"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(e.expected, vec![Expected::Item('b')]),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_stops_a_repetition() {
+        let response = a_char('a')
+            .and(cut(a_char('b')))
+            .opt_rep()
+            .parse(CharStream::new("aba"));
+
+        assert_eq!(response.fold(|_, _, _| false, |_, _| true), true);
+    }
+
+    #[test]
+    fn it_stops_an_option() {
+        let response = cut(a_char('a')).opt().parse(CharStream::new("b"));
+
+        assert_eq!(response.fold(|_, _, _| false, |_, _| true), true);
+    }
+
+    #[test]
+    fn it_backtracks_a_cut_with_try() {
+        let response = a_try(a_char('a').cut())
+            .or(a_char('b'))
+            .parse(CharStream::new("b"));
+
+        assert_eq!(response.fold(|v, _, _| v == 'b', |_, _| false), true);
+    }
+}
@@ -0,0 +1,119 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_diagnostic {
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::a_char;
+    use celma_v0_core::parser::diagnostic::{Style, render};
+    use celma_v0_core::parser::literal::string;
+    use celma_v0_core::parser::response::Response::Reject;
+    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::stream::char_stream::CharStream;
+
+    #[test]
+    fn it_renders_a_plain_diagnostic() {
+        let input = CharStream::new("abc");
+        let response = string("abd").parse(input.clone());
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                render(&input, &e, Style::Plain),
+                "error: expected \"abd\", found 'c'\n --> 1:3\n  |\n1 | abc\n  |   ^\n"
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_renders_the_offending_line() {
+        let input = CharStream::new("a\nab\nc");
+        let response = a_char('a')
+            .and(a_char('\n'))
+            .and(a_char('a'))
+            .and(a_char('c'))
+            .parse(input.clone());
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                render(&input, &e, Style::Plain),
+                "error: expected 'c', found 'b'\n --> 2:2\n  |\n2 | ab\n  |  ^\n"
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_renders_an_end_of_stream() {
+        let input = CharStream::new("a");
+        let response = a_char('a').and(a_char('b')).parse(input.clone());
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                render(&input, &e, Style::Plain),
+                "error: expected 'b', found end of stream\n --> 1:2\n  |\n1 | a\n  |  ^\n"
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_renders_an_ansi_diagnostic() {
+        let input = CharStream::new("b");
+        let response = a_char('a').parse(input.clone());
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                render(&input, &e, Style::Ansi),
+                "\x1b[1;31merror\x1b[0m\x1b[1m: expected 'a', found 'b'\x1b[0m\n \
+                 \x1b[1;34m-->\x1b[0m 1:1\n  \x1b[1;34m|\x1b[0m\n\x1b[1;34m1 |\x1b[0m b\n  \
+                 \x1b[1;34m|\x1b[0m \x1b[1;31m^\x1b[0m\n"
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_renders_from_a_starting_position() {
+        let input = CharStream::new_with_position("ab\ncd", (40, 3, 5));
+        let response = string("ab")
+            .and(a_char('\n'))
+            .and(string("ce"))
+            .parse(input.clone());
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                render(&input, &e, Style::Plain),
+                "error: expected \"ce\", found 'd'\n --> 4:2\n  |\n4 | cd\n  |  ^\n"
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_renders_the_first_line_from_a_starting_column() {
+        let input = CharStream::new_with_position("abc", (40, 3, 5));
+        let response = string("abd").parse(input.clone());
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                render(&input, &e, Style::Plain),
+                "error: expected \"abd\", found 'c'\n --> 3:8\n  |\n3 | abc\n  |   ^\n"
+            ),
+            _ => panic!(),
+        }
+    }
+}
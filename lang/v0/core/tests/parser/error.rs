@@ -0,0 +1,195 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_error {
+    use celma_v0_core::parser::a_try::a_try;
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::{a_char, digit};
+    use celma_v0_core::parser::core::{any, eos};
+    use celma_v0_core::parser::error::{Expected, ParseError};
+    use celma_v0_core::parser::literal::string;
+    use celma_v0_core::parser::option::OptionalOperation;
+    use celma_v0_core::parser::or::OrOperation;
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::response::Response::Reject;
+    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::stream::char_stream::CharStream;
+
+    #[test]
+    fn it_reports_an_expected_character() {
+        let response = a_char('a').parse(CharStream::new("b"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e,
+                ParseError::new((0, 1, 0, 0), Some('b'), vec![Expected::Item('a')])
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_reports_an_expected_range() {
+        let response = digit().parse(CharStream::new(""));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e,
+                ParseError::new((0, 1, 0, 0), None, vec![Expected::Range('0', '9')])
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_reports_an_expected_string_at_the_mismatch() {
+        let response = string("null").parse(CharStream::new("nul!"));
+
+        match response {
+            Reject(_, c, e) => {
+                assert_eq!(c, false);
+                assert_eq!(
+                    e,
+                    ParseError::new(
+                        (3, 1, 3, 3),
+                        Some('!'),
+                        vec![Expected::Label(String::from("\"null\""))]
+                    )
+                )
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_reports_an_expected_end_of_stream() {
+        let response = a_char('a').rep().and(eos()).parse(CharStream::new("aab"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e,
+                ParseError::new(
+                    (2, 1, 2, 2),
+                    Some('b'),
+                    vec![Expected::Item('a'), Expected::EndOfStream]
+                )
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_reports_an_unexpected_end_of_stream() {
+        let response = any().parse(CharStream::new(""));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(e.to_string(), "unexpected end of stream"),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_renders_expected_items() {
+        let error = ParseError::new(
+            (0, 1, 0, 0),
+            Some('c'),
+            vec![
+                Expected::Item('a'),
+                Expected::Item('b'),
+                Expected::EndOfStream,
+            ],
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "expected 'a', 'b' or end of stream, found 'c'"
+        );
+    }
+
+    #[test]
+    fn it_merges_alternatives_at_the_same_position() {
+        let response = a_char('a').or(a_char('b')).parse(CharStream::new("c"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e,
+                ParseError::new(
+                    (0, 1, 0, 0),
+                    Some('c'),
+                    vec![Expected::Item('a'), Expected::Item('b')]
+                )
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_keeps_the_furthest_alternative_failure() {
+        let response = a_try(a_char('a').and(a_char('b')))
+            .or(a_char('a').and(a_char('c')))
+            .parse(CharStream::new("ad"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e,
+                ParseError::new(
+                    (1, 1, 1, 1),
+                    Some('d'),
+                    vec![Expected::Item('b'), Expected::Item('c')]
+                )
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_merges_an_optional_failure_with_the_next_one() {
+        let response = a_char('a')
+            .opt()
+            .and(a_char('b'))
+            .parse(CharStream::new("c"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e,
+                ParseError::new(
+                    (0, 1, 0, 0),
+                    Some('c'),
+                    vec![Expected::Item('a'), Expected::Item('b')]
+                )
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_drops_a_hint_behind_the_failure() {
+        let response = a_char('a')
+            .opt()
+            .and(a_char('b'))
+            .and(a_char('c'))
+            .parse(CharStream::new("bd"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e,
+                ParseError::new((1, 1, 1, 1), Some('d'), vec![Expected::Item('c')])
+            ),
+            _ => panic!(),
+        }
+    }
+}
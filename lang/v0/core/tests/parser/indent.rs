@@ -0,0 +1,140 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+#[cfg(test)]
+mod tests_indent {
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::{a_char, alpha};
+    use celma_v0_core::parser::core::any;
+    use celma_v0_core::parser::indent::{
+        aligned_many, column, indent_guard, indented_block, same_column,
+    };
+    use celma_v0_core::parser::map::MapOperation;
+    use celma_v0_core::parser::or::OrOperation;
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::specs::{Combine, Parse, parse_str};
+    use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::parser_stream::ParserStream;
+    use celma_v0_core::stream::specs::{Indentable, Len, Located};
+
+    fn layout<S>() -> impl Parse<Vec<char>, S> + Combine<Vec<char>>
+    where
+        S: Indentable<Item = char>,
+    {
+        a_char('\n').or(a_char(' ')).opt_rep()
+    }
+
+    fn item<S>() -> impl Parse<char, S> + Combine<char>
+    where
+        S: Indentable<Item = char>,
+    {
+        alpha().and_left(layout())
+    }
+
+    #[test]
+    fn it_parse_aligned_items() {
+        let response = parse_str(&aligned_many(item()), "a\nb\nc");
+
+        assert_eq!(response, Ok(vec!['a', 'b', 'c']));
+    }
+
+    #[test]
+    fn it_stops_on_a_misaligned_item() {
+        let response = aligned_many(item()).parse(CharStream::new("a\nb\n c"));
+
+        assert_eq!(
+            response.fold(|v, s, _| (v, s.len()), |_, _| (vec![], 0)),
+            (vec!['a', 'b'], 1)
+        );
+    }
+
+    #[test]
+    fn it_parse_an_indented_block() {
+        let header = alpha().and_left(a_char(':')).and_left(layout());
+        let parser = indented_block(header, item()).and_left(item());
+
+        let response = parse_str(&parser, "x:\n  a\n  b\ny");
+
+        assert_eq!(response, Ok(('x', vec!['a', 'b'])));
+    }
+
+    #[test]
+    fn it_rejects_a_not_indented_block() {
+        let header = alpha().and_left(a_char(':')).and_left(layout());
+        let parser = indented_block(header, item());
+
+        let response = parse_str(&parser, "x:\na");
+
+        assert_eq!(
+            response.map_err(|e| e.to_string()),
+            Err(String::from(
+                "expected '\\n', ' ' or column greater than 0, found 'a'"
+            ))
+        );
+    }
+
+    #[test]
+    fn it_parse_at_the_same_column() {
+        let parser = a_char(' ').opt_rep().and_right(same_column(2, a_char('a')));
+
+        assert_eq!(
+            (
+                parse_str(&parser, "  a").is_ok(),
+                parse_str(&parser, " a").is_err()
+            ),
+            (true, true)
+        );
+    }
+
+    #[test]
+    fn it_guards_the_indentation() {
+        let parser = a_char(' ')
+            .opt_rep()
+            .and_right(indent_guard(|c| c > 0))
+            .and_right(column());
+
+        assert_eq!(
+            (parse_str(&parser, "   "), parse_str(&parser, "").is_err()),
+            (Ok(3), true)
+        );
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    struct Token(usize, char);
+
+    impl Located for Token {
+        fn column(&self) -> usize {
+            self.0
+        }
+    }
+
+    #[test]
+    fn it_parse_aligned_tokens() {
+        let lexer = a_char('\n')
+            .or(a_char(' '))
+            .opt_rep()
+            .and_right(column())
+            .and(alpha())
+            .map(|(c, a)| Token(c, a));
+        let stream = ParserStream::new(&lexer, CharStream::new("a\nb\n c"));
+
+        let response = aligned_many(any()).parse(stream);
+
+        assert_eq!(
+            response.fold(|v, _, _| v, |_, _| vec![]),
+            vec![Token(0, 'a'), Token(0, 'b')]
+        );
+    }
+}
@@ -0,0 +1,71 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_label {
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::{a_char, digit};
+    use celma_v0_core::parser::error::{Expected, ParseError};
+    use celma_v0_core::parser::label::{LabelOperation, label};
+    use celma_v0_core::parser::or::OrOperation;
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::response::Response::Reject;
+    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::stream::char_stream::CharStream;
+
+    #[test]
+    fn it_parse_a_labelled_parser() {
+        let response = label(digit().rep(), "number").parse(CharStream::new("12"));
+
+        assert_eq!(response.fold(|v, _, _| v.len() == 2, |_, _| false), true);
+    }
+
+    #[test]
+    fn it_replaces_expectations_with_a_label() {
+        let response = a_char('a')
+            .or(a_char('b'))
+            .label("letter")
+            .parse(CharStream::new("c"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e,
+                ParseError::new(
+                    (0, 1, 0, 0),
+                    Some('c'),
+                    vec![Expected::Label(String::from("letter"))]
+                )
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_keeps_expectations_once_input_is_consumed() {
+        let response = a_char('a')
+            .and(a_char('b'))
+            .label("ab")
+            .parse(CharStream::new("ac"));
+
+        match response {
+            Reject(_, true, e) => assert_eq!(
+                e,
+                ParseError::new((1, 1, 1, 1), Some('c'), vec![Expected::Item('b')])
+            ),
+            _ => panic!(),
+        }
+    }
+}
@@ -16,8 +16,11 @@
 
 #[cfg(test)]
 mod tests_lazy {
+    use std::cell::Cell;
+
     use celma_v0_core::parser::char::a_char;
     use celma_v0_core::parser::lazy::lazy;
+    use celma_v0_core::parser::repeat::RepeatOperation;
     use celma_v0_core::parser::specs::Parse;
     use celma_v0_core::stream::char_stream::CharStream;
 
@@ -27,6 +30,21 @@ mod tests_lazy {
 
         assert_eq!(response.fold(|v, _, _| v == 'a', |_, _| false), true);
     }
+
+    #[test]
+    fn it_builds_the_parser_once() {
+        let count = Cell::new(0);
+        let parser = lazy(|| {
+            count.set(count.get() + 1);
+            a_char('a')
+        })
+        .rep();
+
+        let response = parser.parse(CharStream::new("aaa"));
+
+        assert_eq!(response.fold(|v, _, _| v.len(), |_, _| 0), 3);
+        assert_eq!(count.get(), 1);
+    }
 }
 
 // -------------------------------------------------------------------------------------------------
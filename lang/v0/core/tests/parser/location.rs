@@ -36,8 +36,8 @@ mod tests_location {
         let response = locate(a_char('a')).parse(CharStream::new("a"));
 
         assert_eq!(
-            response.fold(|v, _, _| v.start, |_, _| (0, 0, 0)),
-            (0, 1, 0)
+            response.fold(|v, _, _| v.start, |_, _| (0, 0, 0, 0)),
+            (0, 1, 0, 0)
         );
     }
 
@@ -45,6 +45,9 @@ mod tests_location {
     fn it_parse_one_character_with_right_end_location() {
         let response = locate(a_char('a')).parse(CharStream::new("a"));
 
-        assert_eq!(response.fold(|v, _, _| v.end, |_, _| (0, 0, 0)), (1, 1, 1));
+        assert_eq!(
+            response.fold(|v, _, _| v.end, |_, _| (0, 0, 0, 0)),
+            (1, 1, 1, 1)
+        );
     }
 }
@@ -17,9 +17,10 @@
 #[cfg(test)]
 mod tests_and {
     use celma_v0_core::parser::and::AndOperation;
-    use celma_v0_core::parser::char::a_char;
-    use celma_v0_core::parser::lookahead::lookahead;
-    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::parser::char::{a_char, alpha};
+    use celma_v0_core::parser::literal::string;
+    use celma_v0_core::parser::lookahead::{followed_by, lookahead, not_followed_by};
+    use celma_v0_core::parser::specs::{Parse, parse_str};
     use celma_v0_core::stream::char_stream::CharStream;
 
     #[test]
@@ -46,4 +47,42 @@ mod tests_and {
 
         assert_eq!(response.fold(|v, _, _| v == 'a', |_, _| false), true);
     }
+
+    #[test]
+    fn it_parse_followed_by_without_consuming() {
+        let response = followed_by(a_char('a'))
+            .and_right(a_char('a'))
+            .parse(CharStream::new("ab"));
+
+        assert_eq!(response.fold(|v, _, _| v == 'a', |_, _| false), true);
+    }
+
+    #[test]
+    fn it_rejects_followed_by_without_consuming() {
+        let response = followed_by(a_char('b')).parse(CharStream::new("ab"));
+
+        assert_eq!(response.fold(|_, _, _| true, |_, c| c), false);
+    }
+
+    #[test]
+    fn it_parse_not_followed_by_without_consuming() {
+        let response = not_followed_by(a_char('b'))
+            .and_right(a_char('a'))
+            .parse(CharStream::new("ab"));
+
+        assert_eq!(response.fold(|v, _, _| v == 'a', |_, _| false), true);
+    }
+
+    #[test]
+    fn it_rejects_a_keyword_prefix() {
+        let keyword = string("let").and_left(not_followed_by(alpha()));
+
+        assert_eq!(
+            (
+                parse_str(&keyword, "let").is_ok(),
+                parse_str(&keyword, "letter").is_err()
+            ),
+            (true, true)
+        );
+    }
 }
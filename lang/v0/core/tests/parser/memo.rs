@@ -0,0 +1,262 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+#[cfg(test)]
+mod tests_memo {
+    use std::cell::Cell;
+
+    use celma_v0_core::parser::a_try::a_try;
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::{a_char, char_in_range, digit};
+    use celma_v0_core::parser::core::{any, parser};
+    use celma_v0_core::parser::lazy::lazy;
+    use celma_v0_core::parser::map::MapOperation;
+    use celma_v0_core::parser::memo::{MemoKey, memo};
+    use celma_v0_core::parser::or::OrOperation;
+    use celma_v0_core::parser::recovery::recover_with;
+    use celma_v0_core::parser::response::Response::Success;
+    use celma_v0_core::parser::specs::{Combine, Parse};
+    use celma_v0_core::stream::array_stream::ArrayStream;
+    use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::memo_stream::MemoStream;
+    use celma_v0_core::stream::parser_stream::ParserStream;
+    use celma_v0_core::stream::recovery_stream::RecoveryStream;
+    use celma_v0_core::stream::specs::Stream;
+    use celma_v0_core::stream::stateful_stream::StatefulStream;
+
+    #[test]
+    fn it_parse_once_with_a_memo_stream() {
+        let count = Cell::new(0);
+        let key = MemoKey::new();
+        let item = || {
+            key.memo(a_char('a').map(|c| {
+                count.set(count.get() + 1);
+                c
+            }))
+        };
+        let parser = a_try(item().and(a_char('b'))).or(item().and(a_char('c')));
+
+        let response = parser.parse(MemoStream::new(CharStream::new("ac")));
+
+        assert_eq!(response.is_success(), true);
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn it_parse_twice_without_a_memo_stream() {
+        let count = Cell::new(0);
+        let key = MemoKey::new();
+        let item = || {
+            key.memo(a_char('a').map(|c| {
+                count.set(count.get() + 1);
+                c
+            }))
+        };
+        let parser = a_try(item().and(a_char('b'))).or(item().and(a_char('c')));
+
+        let response = parser.parse(CharStream::new("ac"));
+
+        assert_eq!(response.is_success(), true);
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn it_recalls_a_reject() {
+        let count = Cell::new(0);
+        let key = MemoKey::new();
+        let item = || {
+            key.memo(lazy(|| {
+                count.set(count.get() + 1);
+                a_char('a')
+            }))
+        };
+        let parser = item().or(item()).or(a_char('b'));
+
+        let response = parser.parse(MemoStream::new(CharStream::new("b")));
+
+        assert_eq!(response.is_success(), true);
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn it_invalidates_the_cache_between_runs() {
+        let count = Cell::new(0);
+        let item = memo(any().map(|c: char| {
+            count.set(count.get() + 1);
+            c
+        }));
+
+        let first = item.parse(MemoStream::new(CharStream::new("a")));
+        let second = item.parse(MemoStream::new(CharStream::new("b")));
+
+        assert_eq!(
+            (first.into_result(), second.into_result(), count.get()),
+            (Ok('a'), Ok('b'), 2)
+        );
+    }
+
+    #[test]
+    fn it_parse_once_with_a_memo_array_stream() {
+        let count = Cell::new(0);
+        let key = MemoKey::new();
+        let item = || {
+            key.memo(any().map(|c: u8| {
+                count.set(count.get() + 1);
+                c
+            }))
+        };
+        let parser = a_try(item().and(any().map(|_: u8| 0))).or(item().map(|c| (c, 1)));
+
+        let response = parser.parse(MemoStream::new(ArrayStream::new(&[1u8])));
+
+        assert_eq!(response.into_result(), Ok((1, 1)));
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    #[allow(clippy::almost_complete_range)]
+    fn it_parse_once_with_a_memo_parser_stream() {
+        let count = Cell::new(0);
+        let lexer = char_in_range('a'..'z');
+        let key = MemoKey::new();
+        let item = || {
+            key.memo(any().map(|c: char| {
+                count.set(count.get() + 1);
+                c
+            }))
+        };
+        let parser = a_try(item().and(a_char('b'))).or(item().and(a_char('c')));
+
+        let stream = ParserStream::new(&lexer, CharStream::new("ac"));
+        let response = parser.parse(MemoStream::new(stream));
+
+        assert_eq!(response.into_result(), Ok(('a', 'c')));
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn it_parse_once_under_a_stateful_stream() {
+        let count = Cell::new(0);
+        let key = MemoKey::new();
+        let item = || {
+            key.memo(a_char('a').map(|c| {
+                count.set(count.get() + 1);
+                c
+            }))
+        };
+        let parser = a_try(item().and(a_char('b'))).or(item().and(a_char('c')));
+
+        let stream = StatefulStream::new(MemoStream::new(CharStream::new("ac")), ());
+        let response = parser.parse(stream);
+
+        assert_eq!(response.into_result(), Ok(('a', 'c')));
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn it_parse_once_under_a_recovery_stream() {
+        let count = Cell::new(0);
+        let key = MemoKey::new();
+        let item = || {
+            key.memo(a_char('a').map(|c| {
+                count.set(count.get() + 1);
+                c
+            }))
+        };
+        let parser = a_try(item().and(a_char('b'))).or(item().and(a_char('c')));
+
+        let stream = RecoveryStream::new(MemoStream::new(CharStream::new("ac")));
+        let response = parser.parse(stream);
+
+        assert_eq!(response.into_result(), Ok(('a', 'c')));
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn it_does_not_recall_errors_recorded_by_another_path() {
+        let count = Cell::new(0);
+        let key = MemoKey::new();
+        let item = || {
+            key.memo(digit().map(|c| {
+                count.set(count.get() + 1);
+                c
+            }))
+        };
+        let parser = a_try(
+            recover_with(a_char('b'), any::<char, _>())
+                .and_right(item())
+                .and_left(a_char('x')),
+        )
+        .or(any().and_right(item()));
+
+        let stream = RecoveryStream::new(MemoStream::new(CharStream::new("a1")));
+
+        match parser.parse(stream) {
+            Success(v, s, _, _) => assert_eq!((v, s.errors().len(), count.get()), ('1', 0, 2)),
+            _ => panic!(),
+        }
+    }
+
+    fn expr<'a, S>() -> impl Parse<i64, S> + Combine<i64> + 'a
+    where
+        S: Stream<Item = char> + 'a,
+    {
+        static KEY: MemoKey = MemoKey::new();
+
+        let number = || digit().map(|c| c.to_digit(10).unwrap() as i64);
+
+        parser(
+            KEY.left_rec(
+                lazy(|| parser(expr()))
+                    .and_left(a_char('-'))
+                    .and(number())
+                    .map(|(l, r)| l - r)
+                    .or(number()),
+            ),
+        )
+    }
+
+    #[test]
+    fn it_parse_a_left_recursive_rule() {
+        let response = expr().parse(MemoStream::new(CharStream::new("8-2-1")));
+
+        assert_eq!(response.into_result(), Ok(5));
+    }
+
+    #[test]
+    fn it_parse_a_left_recursive_seed() {
+        let response = expr().parse(MemoStream::new(CharStream::new("8")));
+
+        assert_eq!(response.into_result(), Ok(8));
+    }
+
+    #[test]
+    fn it_parse_a_left_recursive_rule_under_a_stateful_stream() {
+        let stream = StatefulStream::new(MemoStream::new(CharStream::new("8-2-1")), ());
+        let response = expr().parse(stream);
+
+        assert_eq!(response.into_result(), Ok(5));
+    }
+
+    #[test]
+    fn it_rejects_a_left_recursive_rule_without_memo_stream() {
+        let response = expr().parse(CharStream::new("8-2-1"));
+
+        assert_eq!(
+            response.into_result().map_err(|e| e.to_string()),
+            Err(String::from("expected memo stream, found '8'"))
+        );
+    }
+}
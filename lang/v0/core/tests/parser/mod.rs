@@ -16,16 +16,33 @@
 
 pub mod a_try;
 pub mod and;
+pub mod binary;
 pub mod bind;
+pub mod bits;
+pub mod chain;
 pub mod char;
 pub mod check;
 pub mod core;
+pub mod cut;
+pub mod diagnostic;
+pub mod error;
+pub mod indent;
+pub mod label;
 pub mod lazy;
 pub mod literal;
 pub mod location;
 pub mod lookahead;
 pub mod map;
+pub mod memo;
 pub mod not;
 pub mod option;
 pub mod or;
+pub mod pratt;
+pub mod recognize;
+pub mod recovery;
+pub mod recursive;
 pub mod repeat;
+pub mod response;
+pub mod specs;
+pub mod state;
+pub mod till;
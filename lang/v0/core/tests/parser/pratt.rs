@@ -0,0 +1,104 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_pratt {
+    use celma_v0_core::parser::char::{char_in_set, digit};
+    use celma_v0_core::parser::map::MapOperation;
+    use celma_v0_core::parser::pratt::{Pratt, pratt};
+    use celma_v0_core::parser::specs::{Combine, Parse, parse_str};
+    use celma_v0_core::stream::specs::Stream;
+
+    fn number<S>() -> impl Parse<i64, S> + Combine<i64>
+    where
+        S: Stream<Item = char>,
+    {
+        digit().map(|c| c.to_digit(10).unwrap() as i64)
+    }
+
+    fn operator<S>() -> impl Parse<char, S> + Combine<char>
+    where
+        S: Stream<Item = char>,
+    {
+        char_in_set(vec!['+', '-', '*', '^', '!'])
+    }
+
+    fn arithmetic<S>()
+    -> Pratt<impl Parse<i64, S> + Combine<i64>, impl Parse<char, S> + Combine<char>, i64, char>
+    where
+        S: Stream<Item = char>,
+    {
+        pratt(number(), operator())
+            .infix_left('+', 1, |l, _, r| l + r)
+            .infix_left('-', 1, |l, _, r| l - r)
+            .infix_left('*', 2, |l, _, r| l * r)
+            .prefix('-', 3, |_, e| -e)
+            .infix_right('^', 4, |l, _, r| l.pow(r as u32))
+            .postfix('!', 5, |e, _| (1..=e).product())
+    }
+
+    #[test]
+    fn it_parse_an_operand() {
+        let response = parse_str(&arithmetic(), "7");
+
+        assert_eq!(response, Ok(7));
+    }
+
+    #[test]
+    fn it_parse_with_precedence() {
+        let response = parse_str(&arithmetic(), "1+2*3");
+
+        assert_eq!(response, Ok(7));
+    }
+
+    #[test]
+    fn it_parse_left_associative_operators() {
+        let response = parse_str(&arithmetic(), "8-2-1");
+
+        assert_eq!(response, Ok(5));
+    }
+
+    #[test]
+    fn it_parse_right_associative_operators() {
+        let response = parse_str(&arithmetic(), "2^3^2");
+
+        assert_eq!(response, Ok(512));
+    }
+
+    #[test]
+    fn it_parse_a_prefix_operator() {
+        let response = parse_str(&arithmetic(), "-2^2+1");
+
+        assert_eq!(response, Ok(-3));
+    }
+
+    #[test]
+    fn it_parse_a_postfix_operator() {
+        let response = parse_str(&arithmetic(), "2*3!");
+
+        assert_eq!(response, Ok(12));
+    }
+
+    #[test]
+    fn it_rejects_a_missing_operand() {
+        let response = parse_str(&arithmetic(), "1+");
+
+        assert_eq!(
+            response.map_err(|e| e.to_string()),
+            Err("expected '0'..'9', found end of stream".to_string())
+        );
+    }
+}
@@ -0,0 +1,65 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_recognize {
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::{a_char, alpha};
+    use celma_v0_core::parser::core::any;
+    use celma_v0_core::parser::recognize::recognize;
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::stream::array_stream::ArrayStream;
+    use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::memo_stream::MemoStream;
+
+    #[test]
+    fn it_recognize_a_str_slice() {
+        let response = recognize(a_char('a').and(a_char('b'))).parse(CharStream::new("abc"));
+
+        assert_eq!(response.fold(|v, _, _| v == "ab", |_, _| false), true);
+    }
+
+    #[test]
+    fn it_recognize_a_multi_byte_str_slice() {
+        let response = a_char('(')
+            .and_right(recognize(any().rep()))
+            .parse(CharStream::new("(变量🦀"));
+
+        assert_eq!(response.fold(|v, _, _| v == "变量🦀", |_, _| false), true);
+    }
+
+    #[test]
+    fn it_recognize_an_array_slice() {
+        let response = recognize(any().and(any())).parse(ArrayStream::new(&[1, 2, 3]));
+
+        assert_eq!(response.fold(|v, _, _| v == [1, 2], |_, _| false), true);
+    }
+
+    #[test]
+    fn it_recognize_through_a_stream_wrapper() {
+        let response = recognize(alpha().rep()).parse(MemoStream::new(CharStream::new("abc1")));
+
+        assert_eq!(response.fold(|v, _, _| v == "abc", |_, _| false), true);
+    }
+
+    #[test]
+    fn it_cannot_recognize() {
+        let response = recognize(a_char('a').and(a_char('b'))).parse(CharStream::new("ac"));
+
+        assert_eq!(response.fold(|_, _, _| false, |_, c| c), true);
+    }
+}
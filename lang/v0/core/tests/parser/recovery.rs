@@ -0,0 +1,137 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_recovery {
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::{a_char, digit};
+    use celma_v0_core::parser::core::eos;
+    use celma_v0_core::parser::error::{Expected, ParseError};
+    use celma_v0_core::parser::recovery::{delimited_recovery, recover_with, skip_until};
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::response::Response::{Reject, Success};
+    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::recovery_stream::RecoveryStream;
+
+    #[test]
+    fn it_parse_without_recovery() {
+        let response = recover_with(digit(), skip_until(a_char(';')))
+            .parse(RecoveryStream::new(CharStream::new("1")));
+
+        match response {
+            Success(v, s, _, _) => {
+                assert_eq!(v, Some('1'));
+                assert_eq!(s.errors().is_empty(), true);
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_recovers_and_records_an_error() {
+        let response = recover_with(digit(), skip_until(a_char(';')))
+            .and(a_char(';'))
+            .parse(RecoveryStream::new(CharStream::new("ab;")));
+
+        match response {
+            Success(v, s, _, _) => {
+                assert_eq!(v, (None, ';'));
+                assert_eq!(
+                    s.errors(),
+                    &[ParseError::new(
+                        (0, 1, 0, 0),
+                        Some('a'),
+                        vec![Expected::Range('0', '9')]
+                    )]
+                );
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_collects_every_error() {
+        let response = recover_with(digit(), skip_until(a_char(';')))
+            .and_left(a_char(';'))
+            .rep()
+            .and_left(eos())
+            .parse(RecoveryStream::new(CharStream::new("1;a;2;b;")));
+
+        match response {
+            Success(v, s, _, _) => {
+                assert_eq!(v, vec![Some('1'), None, Some('2'), None]);
+                assert_eq!(
+                    s.errors().iter().map(|e| e.position.0).collect::<Vec<_>>(),
+                    vec![2, 6]
+                );
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_collects_many_errors() {
+        let source = "a;".repeat(100_000);
+        let response = recover_with(digit(), skip_until(a_char(';')))
+            .and_left(a_char(';'))
+            .rep()
+            .and_left(eos())
+            .parse(RecoveryStream::new(CharStream::new(&source)));
+
+        match response {
+            Success(_, s, _, _) => assert_eq!(s.errors().len(), 100_000),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_rejects_when_recovery_does_not_consume() {
+        let response = recover_with(digit(), skip_until(a_char(';')))
+            .parse(RecoveryStream::new(CharStream::new(";")));
+
+        assert_eq!(response.fold(|_, _, _| false, |_, _| true), true);
+    }
+
+    #[test]
+    fn it_recovers_a_delimited_block() {
+        let block = a_char('(').and(digit()).and(a_char(')'));
+        let response = recover_with(block, delimited_recovery('(', ')'))
+            .and_right(digit())
+            .parse(RecoveryStream::new(CharStream::new("(a(b))2")));
+
+        match response {
+            Success(v, s, _, _) => {
+                assert_eq!(v, '2');
+                assert_eq!(s.errors().len(), 1);
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_rejects_an_unbalanced_delimited_block() {
+        let response = delimited_recovery('(', ')').parse(CharStream::new("((a)"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e,
+                ParseError::new((4, 1, 4, 4), None, vec![Expected::Item(')')])
+            ),
+            _ => panic!(),
+        }
+    }
+}
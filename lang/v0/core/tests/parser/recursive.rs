@@ -0,0 +1,82 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+#[cfg(test)]
+mod tests_recursive {
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::a_char;
+    use celma_v0_core::parser::map::MapOperation;
+    use celma_v0_core::parser::option::OptionalOperation;
+    use celma_v0_core::parser::or::OrOperation;
+    use celma_v0_core::parser::recursive::{Recursive, recursive};
+    use celma_v0_core::parser::specs::{Parse, parse_str};
+    use celma_v0_core::stream::char_stream::CharStream;
+
+    #[test]
+    fn it_parse_a_recursive_parser() {
+        let nested = recursive(|nested| {
+            a_char('(')
+                .and_right(nested.opt())
+                .and_left(a_char(')'))
+                .map(|n: Option<usize>| n.map_or(1, |n| n + 1))
+        });
+
+        assert_eq!(parse_str(&nested, "((()))"), Ok(3));
+    }
+
+    #[test]
+    fn it_parse_a_deeply_nested_input() {
+        let source = format!("{}{}", "[".repeat(100), "]".repeat(100));
+        let nested = recursive(|nested| {
+            a_char('[')
+                .and_right(nested.opt())
+                .and_left(a_char(']'))
+                .map(|n: Option<usize>| n.map_or(1, |n| n + 1))
+        });
+
+        assert_eq!(parse_str(&nested, source.as_str()), Ok(100));
+    }
+
+    #[test]
+    fn it_parse_mutually_recursive_parsers() {
+        let even = Recursive::new();
+        let odd = Recursive::new();
+
+        even.define(
+            a_char('a')
+                .and_right(odd.reference())
+                .or(a_char('.').map(|_| true)),
+        );
+        odd.define(
+            a_char('a')
+                .and_right(even.reference())
+                .or(a_char('.').map(|_| false)),
+        );
+
+        let even = even.hold(odd);
+
+        assert_eq!(
+            (parse_str(&even, "aa."), parse_str(&even, "aaa.")),
+            (Ok(true), Ok(false))
+        );
+    }
+
+    #[test]
+    fn it_rejects_an_undefined_parser() {
+        let undefined: Recursive<char, _> = Recursive::new();
+
+        assert_eq!(undefined.parse(CharStream::new("a")).is_success(), false);
+    }
+}
@@ -49,3 +49,201 @@ mod tests_repeat {
         assert_eq!(response.fold(|v, _, _| v.len() == 3, |_, _| false), true);
     }
 }
+
+#[cfg(test)]
+mod tests_sep_by {
+    use celma_v0_core::parser::char::{a_char, digit};
+    use celma_v0_core::parser::repeat::{
+        SepByOperation, Trailing, end_by, sep_by, sep_by1, sep_end_by,
+    };
+    use celma_v0_core::parser::specs::parse_str;
+
+    #[test]
+    fn it_parse_an_empty_separated_list() {
+        let response = parse_str(&sep_by(digit(), a_char(',')), "");
+
+        assert_eq!(response, Ok(vec![]));
+    }
+
+    #[test]
+    fn it_parse_a_separated_list() {
+        let response = parse_str(&digit().sep_by(a_char(',')), "1,2,3");
+
+        assert_eq!(response, Ok(vec!['1', '2', '3']));
+    }
+
+    #[test]
+    fn it_rejects_a_trailing_separator() {
+        let response = parse_str(&digit().sep_by(a_char(',')), "1,2,");
+
+        assert_eq!(response.is_err(), true);
+    }
+
+    #[test]
+    fn it_rejects_an_empty_list_with_sep_by1() {
+        let response = parse_str(&sep_by1(digit(), a_char(',')), "");
+
+        assert_eq!(response.is_err(), true);
+    }
+
+    #[test]
+    fn it_parse_a_list_with_sep_by1() {
+        let response = parse_str(&sep_by1(digit(), a_char(',')), "1");
+
+        assert_eq!(response, Ok(vec!['1']));
+    }
+
+    #[test]
+    fn it_parse_a_terminated_list() {
+        let response = parse_str(&end_by(digit(), a_char(';')), "1;2;");
+
+        assert_eq!(response, Ok(vec!['1', '2']));
+    }
+
+    #[test]
+    fn it_rejects_a_missing_terminator() {
+        let response = parse_str(&end_by(digit(), a_char(';')), "1;2");
+
+        assert_eq!(response.is_err(), true);
+    }
+
+    #[test]
+    fn it_parse_an_optional_trailing_separator() {
+        let with = parse_str(&sep_end_by(digit(), a_char(',')), "1,2,");
+        let without = parse_str(&sep_end_by(digit(), a_char(',')), "1,2");
+
+        assert_eq!((with, without), (Ok(vec!['1', '2']), Ok(vec!['1', '2'])));
+    }
+
+    #[test]
+    fn it_configures_the_trailing_policy() {
+        let response = parse_str(
+            &digit()
+                .sep_by1(a_char(','))
+                .with_trailing(Trailing::Optional),
+            "1,",
+        );
+
+        assert_eq!(response, Ok(vec!['1']));
+    }
+}
+
+#[cfg(test)]
+mod tests_bounded_repeat {
+    use celma_v0_core::parser::char::digit;
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::specs::{Parse, parse_str};
+    use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::specs::Len;
+
+    #[test]
+    fn it_parse_exactly_n_items() {
+        let response = parse_str(&digit().times(4), "2025");
+
+        assert_eq!(response, Ok(vec!['2', '0', '2', '5']));
+    }
+
+    #[test]
+    fn it_stops_after_n_items() {
+        let response = digit().times(2).parse(CharStream::new("123"));
+
+        assert_eq!(
+            response.fold(|v, s, _| (v, s.len()), |_, _| (vec![], 0)),
+            (vec!['1', '2'], 1)
+        );
+    }
+
+    #[test]
+    fn it_rejects_less_than_n_items() {
+        let response = parse_str(&digit().times(4), "202");
+
+        assert_eq!(response.is_err(), true);
+    }
+
+    #[test]
+    fn it_parse_at_least_n_items() {
+        let response = parse_str(&digit().at_least(2), "12345");
+
+        assert_eq!(response.map(|v| v.len()), Ok(5));
+    }
+
+    #[test]
+    fn it_rejects_less_than_at_least_n_items() {
+        let response = parse_str(&digit().at_least(2), "1");
+
+        assert_eq!(response.is_err(), true);
+    }
+
+    #[test]
+    fn it_parse_between_n_and_m_items() {
+        let two = parse_str(&digit().between(2, 3), "12");
+        let three = parse_str(&digit().between(2, 3), "123");
+        let four = parse_str(&digit().between(2, 3), "1234");
+
+        assert_eq!(
+            (two.is_ok(), three.is_ok(), four.is_err()),
+            (true, true, true)
+        );
+    }
+
+    #[test]
+    fn it_checks_between_n_and_m_items() {
+        let response = digit().between(2, 3).check(CharStream::new("1234"));
+
+        assert_eq!(response.fold(|_, s, _| s.len(), |_, _| 0), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot repeat between 3 and 2 times")]
+    fn it_refuses_unordered_bounds() {
+        digit().between(3, 2).check(CharStream::new("1234"));
+    }
+}
+
+#[cfg(test)]
+mod tests_fold_rep {
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::{a_char, digit};
+    use celma_v0_core::parser::core::eos;
+    use celma_v0_core::parser::repeat::{RepeatOperation, skip_many, skip_many1};
+    use celma_v0_core::parser::specs::{Parse, parse_str};
+    use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::specs::Len;
+
+    #[test]
+    fn it_folds_repeated_items() {
+        let parser = digit().fold_rep(0u32, |a, c| a * 10 + c.to_digit(10).unwrap());
+
+        assert_eq!(parse_str(&parser, "1234"), Ok(1234));
+    }
+
+    #[test]
+    fn it_folds_no_item_to_the_initial_value() {
+        let parser = digit().fold_rep(0u32, |a, c| a * 10 + c.to_digit(10).unwrap());
+
+        assert_eq!(parse_str(&parser, ""), Ok(0));
+    }
+
+    #[test]
+    fn it_skips_many_items() {
+        let response = skip_many(a_char(' ')).parse(CharStream::new("   a"));
+
+        assert_eq!(response.fold(|_, s, _| s.len(), |_, _| 0), 1);
+    }
+
+    #[test]
+    fn it_skips_no_item() {
+        let response = skip_many(a_char(' '))
+            .and_right(eos())
+            .parse(CharStream::new(""));
+
+        assert_eq!(response.is_success(), true);
+    }
+
+    #[test]
+    fn it_rejects_skipping_no_item_at_least_once() {
+        let response = skip_many1(a_char(' ')).parse(CharStream::new("a"));
+
+        assert_eq!(response.is_success(), false);
+    }
+}
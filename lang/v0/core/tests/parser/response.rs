@@ -0,0 +1,92 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_response {
+    use celma_v0_core::parser::char::{a_char, digit};
+    use celma_v0_core::parser::error::{Expected, ParseError};
+    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::stream::char_stream::CharStream;
+
+    #[test]
+    fn it_is_a_success() {
+        let response = digit().parse(CharStream::new("1"));
+
+        assert_eq!(response.is_success(), true);
+    }
+
+    #[test]
+    fn it_is_not_a_success() {
+        let response = digit().parse(CharStream::new("a"));
+
+        assert_eq!(response.is_success(), false);
+    }
+
+    #[test]
+    fn it_converts_a_success_into_a_result() {
+        let response = digit().parse(CharStream::new("1"));
+
+        assert_eq!(response.into_result(), Ok('1'));
+    }
+
+    #[test]
+    fn it_converts_a_reject_into_a_result() {
+        let response = digit().parse(CharStream::new("a"));
+
+        assert_eq!(
+            response.into_result(),
+            Err(ParseError::new(
+                (0, 1, 0, 0),
+                Some('a'),
+                vec![Expected::Range('0', '9')]
+            ))
+        );
+    }
+
+    #[test]
+    fn it_chains_a_response() {
+        let response = digit()
+            .parse(CharStream::new("1a"))
+            .and_then(|v, s| a_char('a').parse(s).map(|c| (v, c)));
+
+        assert_eq!(response.into_result(), Ok(('1', 'a')));
+    }
+
+    #[test]
+    fn it_chains_a_rejected_response() {
+        let response = digit()
+            .parse(CharStream::new("1b"))
+            .and_then(|_, s| a_char('a').parse(s));
+
+        assert_eq!(response.fold(|_, _, _| false, |_, c| c), true);
+    }
+
+    #[test]
+    fn it_maps_an_error() {
+        let response = digit().parse(CharStream::new("a")).map_err(|e| {
+            ParseError::new(
+                e.position,
+                e.found,
+                vec![Expected::Label("digit".to_string())],
+            )
+        });
+
+        assert_eq!(
+            response.into_result().map_err(|e| e.to_string()),
+            Err("expected digit, found 'a'".to_string())
+        );
+    }
+}
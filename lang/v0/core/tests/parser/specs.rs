@@ -0,0 +1,80 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_specs {
+    use celma_v0_core::parser::char::{a_char, digit};
+    use celma_v0_core::parser::error::{Expected, ParseError};
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::specs::{parse_all, parse_str};
+    use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::partial_stream::PartialStream;
+
+    #[test]
+    fn it_parse_a_whole_string() {
+        let response = parse_str(&digit().rep(), "123");
+
+        assert_eq!(response, Ok(vec!['1', '2', '3']));
+    }
+
+    #[test]
+    fn it_parse_a_whole_stream() {
+        let response = parse_all(&a_char('a'), CharStream::new("a"));
+
+        assert_eq!(response, Ok('a'));
+    }
+
+    #[test]
+    fn it_rejects_a_remaining_input() {
+        let response = parse_str(&digit().rep(), "12a");
+
+        assert_eq!(
+            response,
+            Err(ParseError::new(
+                (2, 1, 2, 2),
+                Some('a'),
+                vec![Expected::Range('0', '9'), Expected::EndOfStream]
+            ))
+        );
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_input() {
+        let response = parse_str(&a_char('a'), "b");
+
+        assert_eq!(
+            response.map_err(|e| e.to_string()),
+            Err("expected 'a', found 'b'".to_string())
+        );
+    }
+
+    #[test]
+    fn it_needs_more_at_the_end_of_a_partial_stream() {
+        let response = parse_all(&a_char('a'), PartialStream::new(CharStream::new("a")));
+
+        assert_eq!(response.map_err(|e| e.incomplete), Err(true));
+    }
+
+    #[test]
+    fn it_parse_a_whole_closed_stream() {
+        let response = parse_all(
+            &a_char('a'),
+            PartialStream::new(CharStream::new("a")).close(),
+        );
+
+        assert_eq!(response, Ok('a'));
+    }
+}
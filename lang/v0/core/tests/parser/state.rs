@@ -0,0 +1,123 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+#[cfg(test)]
+mod tests_state {
+    use celma_v0_core::parser::a_try::a_try;
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::bind::BindOperation;
+    use celma_v0_core::parser::char::{a_char, alpha};
+    use celma_v0_core::parser::core::returns;
+    use celma_v0_core::parser::map::MapOperation;
+    use celma_v0_core::parser::memo::MemoKey;
+    use celma_v0_core::parser::or::OrOperation;
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::specs::{Parse, parse_all};
+    use celma_v0_core::parser::state::{get_state, put_state, update_state};
+    use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::memo_stream::MemoStream;
+    use celma_v0_core::stream::stateful_stream::StatefulStream;
+
+    #[test]
+    fn it_gets_the_initial_state() {
+        let response = get_state().parse(StatefulStream::new(CharStream::new(""), 42));
+
+        assert_eq!(response.into_result(), Ok(42));
+    }
+
+    #[test]
+    fn it_puts_a_state() {
+        let parser = put_state(1).and_right(get_state());
+        let response = parser.parse(StatefulStream::new(CharStream::new(""), 0));
+
+        assert_eq!(response.into_result(), Ok(1));
+    }
+
+    #[test]
+    fn it_counts_items_in_the_state() {
+        let parser = a_char('a')
+            .and_left(update_state(|n: &usize| n + 1))
+            .opt_rep()
+            .and_right(get_state());
+
+        let response = parse_all(&parser, StatefulStream::new(CharStream::new("aaa"), 0));
+
+        assert_eq!(response, Ok(3));
+    }
+
+    #[test]
+    fn it_restores_the_state_on_backtracking() {
+        let parser = a_try(
+            update_state(|n: &usize| n + 1)
+                .and_right(a_char('a'))
+                .and_right(a_char('b'))
+                .and_right(get_state()),
+        )
+        .or(a_char('a').and_right(a_char('c')).and_right(get_state()));
+
+        let response = parse_all(&parser, StatefulStream::new(CharStream::new("ac"), 0));
+
+        assert_eq!(response, Ok(0));
+    }
+
+    #[test]
+    fn it_binds_with_the_state() {
+        let name = || alpha().rep().map(|v| v.into_iter().collect::<String>());
+        let typedef = a_char('#')
+            .and_right(name())
+            .bind(|n| update_state(move |t: &Vec<String>| [t.clone(), vec![n.clone()]].concat()))
+            .and_left(a_char(';'));
+        let is_type = name()
+            .and(get_state())
+            .bind(|(n, t): (String, Vec<String>)| returns(t.contains(&n)));
+        let parser = typedef
+            .opt_rep()
+            .and_right(is_type.and_left(a_char(';')).rep());
+
+        let response = parse_all(
+            &parser,
+            StatefulStream::new(CharStream::new("#T;T;x;"), vec![]),
+        );
+
+        assert_eq!(response, Ok(vec![true, false]));
+    }
+
+    #[test]
+    fn it_does_not_recall_a_memo_parsed_with_another_state() {
+        let key = MemoKey::new();
+        let item = || key.memo(a_char('a').and_right(get_state()));
+        let parser = a_try(put_state(1).and_right(item()).and_left(a_char('b')))
+            .or(put_state(2).and_right(item()));
+
+        let stream = StatefulStream::new(MemoStream::new(CharStream::new("a")), 0);
+        let response = parser.parse(stream);
+
+        assert_eq!(response.into_result(), Ok(2));
+    }
+
+    #[test]
+    fn it_recalls_the_state_left_by_a_memo() {
+        let key = MemoKey::new();
+        let item = || key.memo(a_char('a').and_left(update_state(|n: &usize| n + 1)));
+        let parser = a_try(item().and_left(a_char('b')))
+            .or(item().and_left(a_char('c')))
+            .and_right(get_state());
+
+        let stream = StatefulStream::new(MemoStream::new(CharStream::new("ac")), 0);
+        let response = parser.parse(stream);
+
+        assert_eq!(response.into_result(), Ok(1));
+    }
+}
@@ -0,0 +1,83 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+#[cfg(test)]
+mod tests_till {
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::a_char;
+    use celma_v0_core::parser::core::any;
+    use celma_v0_core::parser::literal::string;
+    use celma_v0_core::parser::specs::{Parse, parse_str};
+    use celma_v0_core::parser::till::{ManyTillOperation, many_till, take_until};
+    use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::specs::Len;
+
+    #[test]
+    fn it_parse_a_comment_till_its_terminator() {
+        let parser = string("/*").and_right(any().many_till(string("*/")));
+
+        assert_eq!(
+            parse_str(&parser, "/* a * b */").map(|(v, _)| v.into_iter().collect::<String>()),
+            Ok(" a * b ".to_string())
+        );
+    }
+
+    #[test]
+    fn it_returns_the_terminator_value() {
+        let parser = many_till(any(), string("*/"));
+
+        assert_eq!(parse_str(&parser, "*/").map(|(_, e)| e), Ok("*/"));
+    }
+
+    #[test]
+    fn it_rejects_a_missing_terminator() {
+        let parser = many_till(any(), string("*/"));
+
+        assert_eq!(
+            parse_str(&parser, "a *").map_err(|e| e.to_string()),
+            Err("expected \"*/\", found end of stream".to_string())
+        );
+    }
+
+    #[test]
+    fn it_takes_items_until_the_terminator() {
+        let response = take_until(string("\r\n")).parse(CharStream::new("a\rb\r\nc"));
+
+        assert_eq!(
+            response.fold(
+                |v, s, _| (v.into_iter().collect::<String>(), s.len()),
+                |_, _| (String::new(), 0)
+            ),
+            ("a\rb".to_string(), 3)
+        );
+    }
+
+    #[test]
+    fn it_takes_no_item_on_a_terminator() {
+        let response = take_until(a_char(';')).parse(CharStream::new(";"));
+
+        assert_eq!(
+            response.fold(|v, _, c| (v.len(), c), |_, _| (1, true)),
+            (0, false)
+        );
+    }
+
+    #[test]
+    fn it_rejects_taking_items_without_terminator() {
+        let parser = take_until(a_char(';'));
+
+        assert_eq!(parse_str(&parser, "ab").is_err(), true);
+    }
+}
@@ -0,0 +1,54 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_bit_stream {
+    use celma_v0_core::stream::bit_stream::BitStream;
+    use celma_v0_core::stream::specs::{Len, Stream};
+
+    #[test]
+    fn it_reads_most_significant_bits_first() {
+        let stream = BitStream::new(&[0b1000_0001]);
+        let (first, stream) = stream.next();
+        let (second, _) = stream.next();
+
+        assert_eq!((first, second), (Some(true), Some(false)));
+    }
+
+    #[test]
+    fn it_counts_bits() {
+        let (_, stream) = BitStream::new(&[0, 0]).next();
+
+        assert_eq!(
+            (stream.position(), stream.len(), stream.is_aligned()),
+            (1, 15, false)
+        );
+    }
+
+    #[test]
+    fn it_ends_after_the_last_byte() {
+        let mut stream = BitStream::new(&[0xff]);
+
+        for _ in 0..8 {
+            stream = stream.next().1;
+        }
+
+        assert_eq!(
+            (stream.next().0, stream.position(), stream.is_aligned()),
+            (None, 8, true)
+        );
+    }
+}
@@ -0,0 +1,57 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_byte_stream {
+    use celma_v0_core::stream::byte_stream::ByteStream;
+    use celma_v0_core::stream::position::Position;
+    use celma_v0_core::stream::specs::{Len, Stream};
+
+    #[test]
+    fn it_counts_bytes() {
+        let (_, stream) = ByteStream::new(&[1, 2, 3]).next();
+
+        assert_eq!(
+            (stream.position(), stream.len(), stream.rest()),
+            (1, 2, &[2, 3][..])
+        );
+    }
+
+    #[test]
+    fn it_ignores_end_lines_by_default() {
+        let stream = ByteStream::new_with_position(b"a\nb", <(usize, usize, usize)>::new());
+        let (_, stream) = stream.next();
+        let (_, stream) = stream.next();
+
+        assert_eq!(stream.position(), (2, 1, 2));
+    }
+
+    #[test]
+    fn it_tracks_end_lines_when_enabled() {
+        let stream = ByteStream::new_with_position(b"a\nb", <(usize, usize, usize)>::new())
+            .with_end_lines(true);
+        let (_, stream) = stream.next();
+        let (_, stream) = stream.next();
+
+        assert_eq!(
+            (
+                stream.position().line_number(),
+                stream.position().char_number()
+            ),
+            (2, 0)
+        );
+    }
+}
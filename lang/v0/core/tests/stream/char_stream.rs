@@ -0,0 +1,111 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_char_stream {
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::a_char;
+    use celma_v0_core::parser::core::{any, eos};
+    use celma_v0_core::parser::literal::string;
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::position::Position;
+    use celma_v0_core::stream::specs::{Len, Stream};
+
+    #[test]
+    fn it_parse_emoji() {
+        let response = any().rep().and_left(eos()).parse(CharStream::new("a🦀b"));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == vec!['a', '🦀', 'b'], |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_parse_cjk() {
+        let response = a_char('变')
+            .and(a_char('量'))
+            .and_left(eos())
+            .parse(CharStream::new("变量"));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == ('变', '量'), |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn it_parse_combining_marks_as_chars() {
+        let response = any()
+            .rep()
+            .and_left(eos())
+            .parse(CharStream::new("e\u{301}t\u{308}"));
+
+        assert_eq!(
+            response.fold(
+                |v, _, _| v == vec!['e', '\u{301}', 't', '\u{308}'],
+                |_, _| false
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn it_tracks_char_index_and_byte_offset() {
+        let stream = CharStream::new("é🦀a");
+        let (_, stream) = stream.next();
+        let (_, stream) = stream.next();
+
+        assert_eq!(
+            (
+                stream.position().offset(),
+                stream.position().byte_offset(),
+                stream.byte_offset(),
+                stream.len()
+            ),
+            (2, 6, 6, 1)
+        );
+        assert_eq!(stream.rest(), "a");
+    }
+
+    #[test]
+    fn it_slices_the_source_with_byte_offsets() {
+        let source = "let 变量 = 1";
+        let response = string("let ").parse(CharStream::new(source));
+
+        match response.fold(|_, s, _| Some(s), |_, _| None) {
+            Some(start) => {
+                let (_, end) = start.next().1.next();
+                let (from, to) = (start.position().byte_offset(), end.position().byte_offset());
+
+                assert_eq!(&source[from..to], "变量");
+            }
+            None => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_steps_lines_with_multi_byte_characters() {
+        let stream = CharStream::new("变\n量");
+        let (_, stream) = stream.next();
+        let (_, stream) = stream.next();
+        let (_, stream) = stream.next();
+
+        assert_eq!(stream.position(), (3, 2, 1, 7));
+    }
+}
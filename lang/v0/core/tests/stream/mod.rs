@@ -14,5 +14,10 @@
    limitations under the License.
 */
 
+pub mod bit_stream;
+pub mod byte_stream;
+pub mod char_stream;
 pub mod iterator_stream;
 pub mod parser_stream;
+pub mod partial_stream;
+pub mod position;
@@ -16,13 +16,17 @@
 
 #[cfg(test)]
 mod tests_parser_stream {
-    use celma_v0_core::parser::char::char_in_range;
+    use std::cell::Cell;
+
+    use celma_v0_core::parser::char::{a_char, char_in_range};
     use celma_v0_core::parser::core::any;
     use celma_v0_core::parser::map::MapOperation;
     use celma_v0_core::parser::repeat::RepeatOperation;
     use celma_v0_core::parser::specs::Parse;
     use celma_v0_core::stream::char_stream::CharStream;
     use celma_v0_core::stream::parser_stream::ParserStream;
+    use celma_v0_core::stream::partial_stream::PartialStream;
+    use celma_v0_core::stream::specs::Stream;
 
     #[derive(Clone, Eq, PartialEq)]
     struct Item(char);
@@ -38,4 +42,21 @@ mod tests_parser_stream {
             true
         );
     }
+
+    #[test]
+    fn it_parse_once_to_know_if_it_is_partial() {
+        let count = Cell::new(0);
+        let parser = a_char('a').map(|c| {
+            count.set(count.get() + 1);
+            Item(c)
+        });
+        let stream = ParserStream::new(&parser, PartialStream::new(CharStream::new("a")));
+        let (_, next) = stream.next();
+
+        assert_eq!(
+            (stream.is_partial(), next.is_partial(), next.is_partial()),
+            (false, true, true)
+        );
+        assert_eq!(count.get(), 1);
+    }
 }
@@ -0,0 +1,151 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_partial_stream {
+    use celma_v0_core::parser::a_try::a_try;
+    use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::a_char;
+    use celma_v0_core::parser::core::eos;
+    use celma_v0_core::parser::literal::string;
+    use celma_v0_core::parser::lookahead::not_followed_by;
+    use celma_v0_core::parser::or::OrOperation;
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::response::Response::{Reject, Success};
+    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::stream::byte_stream::ByteStream;
+    use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::frame_stream::FrameStream;
+    use celma_v0_core::stream::partial_stream::PartialStream;
+    use celma_v0_core::stream::position::Position;
+    use celma_v0_core::stream::specs::Stream;
+
+    #[test]
+    fn it_is_partial_until_closed() {
+        let stream = PartialStream::new(CharStream::new("a"));
+
+        assert_eq!(
+            (stream.is_partial(), stream.clone().close().is_partial()),
+            (true, false)
+        );
+    }
+
+    #[test]
+    fn it_needs_more_at_the_end() {
+        let response = a_char('a').parse(PartialStream::new(CharStream::new("")));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                (e.incomplete, e.committed, e.position.offset()),
+                (true, true, 0)
+            ),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_rejects_a_mismatch_without_waiting() {
+        let response = a_char('a').parse(PartialStream::new(CharStream::new("b")));
+
+        assert_eq!(
+            (response.is_success(), response.is_incomplete()),
+            (false, false)
+        );
+    }
+
+    #[test]
+    fn it_does_not_accept_the_end_of_stream() {
+        let response = eos().parse(PartialStream::new(CharStream::new("")));
+
+        assert!(response.is_incomplete());
+    }
+
+    #[test]
+    fn it_accepts_the_end_of_a_closed_stream() {
+        let response = eos().parse(PartialStream::new(CharStream::new("")).close());
+
+        assert!(response.is_success());
+    }
+
+    #[test]
+    fn it_does_not_try_the_alternative() {
+        let parser = string("abc").or(string("ab"));
+        let response = parser.parse(PartialStream::new(CharStream::new("ab")));
+
+        assert!(response.is_incomplete());
+    }
+
+    #[test]
+    fn it_keeps_incomplete_through_a_try() {
+        let parser = a_try(string("abc")).or(string("ab"));
+        let response = parser.parse(PartialStream::new(CharStream::new("ab")));
+
+        assert!(response.is_incomplete());
+    }
+
+    #[test]
+    fn it_needs_more_for_a_negative_lookahead() {
+        let parser = a_char('a').and(not_followed_by(a_char('b')));
+        let response = parser.parse(PartialStream::new(CharStream::new("a")));
+
+        assert!(response.is_incomplete());
+    }
+
+    #[test]
+    fn it_stops_a_repetition_on_a_mismatch() {
+        let parser = a_char('a').rep();
+        let response = parser.parse(PartialStream::new(CharStream::new("aab")));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, vec!['a', 'a']),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_waits_at_the_end_of_a_repetition() {
+        let parser = a_char('a').rep();
+        let response = parser.parse(PartialStream::new(CharStream::new("aa")));
+
+        assert!(response.is_incomplete());
+    }
+
+    #[test]
+    fn it_stops_a_repetition_on_a_closed_stream() {
+        let parser = a_char('a').rep();
+        let response = parser.parse(PartialStream::new(CharStream::new("aa")).close());
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, vec!['a', 'a']),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_is_partial_until_the_end_of_a_frame() {
+        let stream = FrameStream::new(PartialStream::new(ByteStream::new(&[1])), 2);
+        let (_, next) = stream.next();
+        let (_, end) = next.next();
+
+        assert_eq!(
+            (
+                next.is_partial(),
+                FrameStream::new(end.stream(), 1).is_partial()
+            ),
+            (true, false)
+        );
+    }
+}
@@ -0,0 +1,34 @@
+/*
+   Copyright 2019-2025 Didier Plaindoux
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+#[cfg(test)]
+mod tests_position {
+    use celma_v0_core::stream::position::Position;
+
+    #[test]
+    fn it_steps_a_column() {
+        let position = <(usize, usize, usize)>::new().step(false);
+
+        assert_eq!((position.line_number(), position.char_number()), (1, 1));
+    }
+
+    #[test]
+    fn it_steps_a_line() {
+        let position = <(usize, usize, usize)>::new().step(false).step(true);
+
+        assert_eq!((position.line_number(), position.char_number()), (2, 0));
+    }
+}
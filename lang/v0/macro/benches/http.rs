@@ -19,6 +19,7 @@ use celma_v0_core::parser::char::{alpha, digit};
 use celma_v0_core::parser::core::eos;
 use celma_v0_core::parser::response::Response::{Reject, Success};
 use celma_v0_core::parser::specs::Parse;
+use celma_v0_core::parser::till::take_until;
 use celma_v0_core::stream::array_stream::ArrayStream;
 use celma_v0_core::stream::position::Position;
 use celma_v0_core::stream::specs::Stream;
@@ -31,13 +32,13 @@ parsec_rules!(
 );
 
 parsec_rules!(
-    let VERB = ("GET" | "POST" | "PUT" | "DELETE" | "HEAD" | "CONNECT" | "PATCH") -> {}
+    let VERB = ("GET" | "POST" | "PUT" | "DELETE" | "HEAD" | "CONNECT" | "PATCH") @ "method" -> {}
     let URI  = ^(' ')+ -> {}
     let VERSION = "HTTP/" digit+ ('.' digit+)? -> {}
     let S  = (' ' | '\t')+ -> {}
     let EOL = ('\r'? '\n') -> {}
-    let NAME = (alpha | '-')+ -> {}
-    let VALUE = ^EOL+ -> {} // Not precise enough
+    let NAME = (alpha | '-')+ @ "header name" -> {}
+    let VALUE = {take_until(EOL())} -> {}
 );
 
 // -------------------------------------------------------------------------------------------------
@@ -51,6 +52,7 @@ fn http_data(b: &mut Bencher) {
     parse(b, data)
 }
 
+#[allow(clippy::clone_on_copy)]
 fn parse(b: &mut Bencher, buffer: &[char]) {
     let stream = ArrayStream::new_with_position(buffer, <usize>::new());
 
@@ -60,8 +62,8 @@ fn parse(b: &mut Bencher, buffer: &[char]) {
             .parse(black_box(stream.clone()));
 
         match response {
-            Success(_, _, _) => (),
-            Reject(s, _) => panic!("parse error for {:?} at {:?}", s.next().0, s.position()),
+            Success(_, _, _, _) => (),
+            Reject(s, _, _) => panic!("parse error for {:?} at {:?}", s.next().0, s.position()),
         }
     });
 }
@@ -27,7 +27,6 @@ use celma_v0_core::parser::response::Response::{Reject, Success};
 use celma_v0_core::parser::specs::Parse;
 use celma_v0_core::stream::array_stream::ArrayStream;
 use celma_v0_core::stream::position::Position;
-use celma_v0_core::stream::specs::Stream;
 use celma_v0_macro::parsec_rules;
 
 #[derive(Clone)]
@@ -40,16 +39,6 @@ pub enum JSON {
     Object(Vec<(String, JSON)>),
 }
 
-fn mk_vec<E>(a: Option<(E, Vec<E>)>) -> Vec<E> {
-    if let Some((a, v)) = a {
-        let mut r = v;
-        r.insert(0, a);
-        r
-    } else {
-        Vec::new()
-    }
-}
-
 fn mk_string(a: Vec<char>) -> String {
     a.into_iter().collect::<String>()
 }
@@ -59,13 +48,13 @@ fn mk_f64(a: Vec<char>) -> f64 {
 }
 
 parsec_rules!(
-    let json:{JSON}          = S _=(string | number | boolean | null | array | object ) S
+    let json:{JSON}          = S _=(string | number | boolean | null | array | object) @ "value" S
     let number:{JSON}        = f=NUMBER                                -> {JSON::Number(f)}
     let string:{JSON}        = s=STRING                                -> {JSON::String(s)}
     let null:{JSON}          = "null"                                  -> {JSON::Null}
     let boolean:{JSON}       = b=("true"|"false")                      -> {JSON::Bool(b=="true")}
-    let array:{JSON}         = ('[' S a=(_=json _=(',' _=json)*)? ']') -> {JSON::Array(mk_vec(a))}
-    let object:{JSON}        = ('{' S a=(_=attr _=(',' _=attr)*)? '}') -> {JSON::Object(mk_vec(a))}
+    let array:{JSON}         = ('[' S a=json % ',' ']')                -> {JSON::Array(a)}
+    let object:{JSON}        = ('{' S a=attr % ',' '}')                -> {JSON::Object(a)}
     let attr:{(String,JSON)} = (S s=STRING S ":" j=json)
 );
 
@@ -129,11 +118,11 @@ fn parse(b: &mut Bencher, buffer: &[char]) {
     let stream = ArrayStream::new_with_position(buffer, <usize>::new());
 
     b.iter(|| {
-        let response = json().and_left(eos()).parse(black_box(stream.clone()));
+        let response = json().and_left(eos()).parse(black_box(stream));
 
         match response {
-            Success(_, _, _) => (),
-            Reject(s, _) => panic!("parse error for {:?} at {:?}", s.next().0, s.position()),
+            Success(_, _, _, _) => (),
+            Reject(_, _, e) => panic!("parse error at {:?}: {}", e.position, e),
         }
     });
 }
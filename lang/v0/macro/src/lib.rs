@@ -19,7 +19,6 @@ use celma_v0_core::parser::response::Response;
 use celma_v0_core::parser::response::Response::{Reject, Success};
 use celma_v0_core::parser::specs::Parse;
 use celma_v0_core::stream::char_stream::CharStream;
-use celma_v0_core::stream::specs::Stream;
 use celma_v0_parser::parser::{celma_parsec, celma_parsec_rules};
 use celma_v0_parser::transpiler::Transpile;
 use syn::Error;
@@ -34,6 +33,21 @@ pub fn parsec(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     conclude_parsing(result)
 }
 
+/// Transpiles rules to parser functions.
+///
+/// ```
+/// celma_v0_macro::parsec_rules!(
+///     let upper:{char} = [:Lu:]
+/// );
+/// ```
+///
+/// Unknown character classes are rejected at compile time:
+///
+/// ```compile_fail
+/// celma_v0_macro::parsec_rules!(
+///     let greek:{char} = [:greek:]
+/// );
+/// ```
 #[proc_macro]
 pub fn parsec_rules(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let source = input.to_string();
@@ -44,14 +58,15 @@ pub fn parsec_rules(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     conclude_parsing(result)
 }
 
-fn conclude_parsing(
-    result: Response<Result<proc_macro2::TokenStream, Error>, CharStream<(usize, usize, usize)>>,
-) -> proc_macro::TokenStream {
+type Transpiled<'a> =
+    Response<Result<proc_macro2::TokenStream, Error>, CharStream<'a, (usize, usize, usize, usize)>>;
+
+fn conclude_parsing(result: Transpiled) -> proc_macro::TokenStream {
     match result {
-        Success(code, _, _) => match code {
+        Success(code, _, _, _) => match code {
             Ok(code) => code.into(),
             Err(err) => panic!("{}", err.into_compile_error()),
         },
-        Reject(s, _) => panic!("Parse error at {:?}", s.position()),
+        Reject(_, _, e) => panic!("Parse error at {:?}: {}", e.position, e),
     }
 }
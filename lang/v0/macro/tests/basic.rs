@@ -48,7 +48,7 @@ mod tests_transpiler {
         let response = bash().and_left(eos()).parse(CharStream::new("Hello"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v, Expr::Seq(vec!(Expr::Text("Hello".to_owned())))),
+            Success(v, _, _, _) => assert_eq!(v, Expr::Seq(vec!(Expr::Text("Hello".to_owned())))),
             _ => assert_eq!(true, false),
         }
     }
@@ -58,7 +58,7 @@ mod tests_transpiler {
         let response = bash().and_left(eos()).parse(CharStream::new("${world}"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v, Expr::Seq(vec!(Expr::Var("world".to_owned())))),
+            Success(v, _, _, _) => assert_eq!(v, Expr::Seq(vec!(Expr::Var("world".to_owned())))),
             _ => assert_eq!(true, false),
         }
     }
@@ -70,7 +70,7 @@ mod tests_transpiler {
             .parse(CharStream::new("Hello ${world}"));
 
         match response {
-            Success(v, _, _) => assert_eq!(
+            Success(v, _, _, _) => assert_eq!(
                 v,
                 Expr::Seq(vec!(
                     Expr::Text("Hello ".to_owned()),
@@ -83,7 +83,7 @@ mod tests_transpiler {
         let response = parsec!(_=expr {eos()}).parse(CharStream::new("1 + 2"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v.eval(), 3.0),
+            Success(v, _, _, _) => assert_eq!(v.eval(), 3.0),
             _ => assert_eq!(true, false),
         }
     }
@@ -95,7 +95,7 @@ mod tests_transpiler {
             .parse(CharStream::new("(1 + -2) * 4"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v.eval(), -4.0),
+            Success(v, _, _, _) => assert_eq!(v.eval(), -4.0),
             _ => assert_eq!(true, false),
         }
     }
@@ -16,6 +16,7 @@
 
 pub mod basic;
 pub mod expression;
+pub mod partial;
 pub mod pipeline;
 pub mod transpiler;
 pub mod transpiler_rules;
@@ -0,0 +1,117 @@
+/*
+ * Copyright 2019-2025 Didier Plaindoux
+=======
+ * Copyright 2019-2021 Didier Plaindoux
+>>>>>>> 45ec19c (Manage compiler warnings and change License header)
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(test)]
+mod tests_partial {
+    use celma_v0_core::parser::char::{alpha, digit};
+    use celma_v0_core::parser::response::Response;
+    use celma_v0_core::parser::response::Response::{Reject, Success};
+    use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::parser::till::take_until;
+    use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::partial_stream::PartialStream;
+    use celma_v0_core::stream::position::Position;
+    use celma_v0_core::stream::specs::Stream;
+    use celma_v0_macro::parsec_rules;
+
+    parsec_rules!(
+        let http_header:{usize} = (request h=(header)* EOL) -> { h.len() }
+        let request = VERB S URI S VERSION EOL -> {}
+        let header = NAME ':' S VALUE EOL -> {}
+    );
+
+    parsec_rules!(
+        let VERB = ("GET" | "POST" | "PUT" | "DELETE" | "HEAD" | "CONNECT" | "PATCH") @ "method" -> {}
+        let URI  = ^(' ')+ -> {}
+        let VERSION = "HTTP/" digit+ ('.' digit+)? -> {}
+        let S  = (' ' | '\t')+ -> {}
+        let EOL = ('\r'? '\n') -> {}
+        let NAME = (alpha | '-')+ @ "header name" -> {}
+        let VALUE = {take_until(EOL())} -> {}
+    );
+
+    // Restarts the parser on the data received so far each time a chunk arrives.
+    fn feed(chunks: &[&str]) -> (usize, Option<(usize, usize)>) {
+        let mut buffer = String::new();
+
+        for (index, chunk) in chunks.iter().enumerate() {
+            buffer.push_str(chunk);
+
+            let stream = PartialStream::new(CharStream::new(&buffer));
+
+            match http_header().parse(stream) {
+                Success(n, s, _, _) => return (index, Some((n, s.position().offset()))),
+                response if response.is_incomplete() => continue,
+                _ => return (index, None),
+            }
+        }
+
+        (chunks.len(), None)
+    }
+
+    #[test]
+    fn it_waits_for_the_end_of_a_request() {
+        let chunks = [
+            "GE",
+            "T /index.html HTT",
+            "P/1.1\r\nHost: exam",
+            "ple.com\r\nAccept: */*\r",
+            "\n\r",
+            "\n",
+        ];
+
+        assert_eq!(feed(&chunks), (5, Some((2, 60))));
+    }
+
+    #[test]
+    fn it_rejects_a_wrong_method_without_waiting() {
+        assert_eq!(feed(&["FOO /", "index.html HTTP/1.1\r\n\r\n"]), (0, None));
+    }
+
+    #[test]
+    fn it_is_incomplete_in_a_choice() {
+        let response = http_header().parse(PartialStream::new(CharStream::new("PO")));
+
+        match response {
+            Reject(_, _, e) => assert_eq!((e.incomplete, e.position.offset()), (true, 2)),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn it_rejects_a_closed_stream() {
+        let stream = PartialStream::new(CharStream::new("GET / HTTP/1.1\r\n")).close();
+        let response: Response<usize, _> = http_header().parse(stream);
+
+        assert_eq!(
+            (response.is_success(), response.is_incomplete()),
+            (false, false)
+        );
+    }
+
+    #[test]
+    fn it_accepts_a_complete_request_before_closing() {
+        let stream = PartialStream::new(CharStream::new("GET / HTTP/1.1\r\n\r\nbody"));
+
+        match http_header().parse(stream) {
+            Success(n, s, _, _) => assert_eq!((n, s.next().0), (0, Some('b'))),
+            _ => panic!(),
+        }
+    }
+}
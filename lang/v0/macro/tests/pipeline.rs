@@ -14,12 +14,15 @@
    limitations under the License.
 */
 
+#![allow(clippy::multiple_bound_locations)]
+
 #[cfg(test)]
 mod tests_transpiler {
     use celma_v0_core::parser::and::AndOperation;
     use celma_v0_core::parser::char::{digit, space};
     use celma_v0_core::parser::core::eos;
     use celma_v0_core::parser::map::MapOperation;
+    use celma_v0_core::parser::pratt::pratt;
     use celma_v0_core::parser::response::Response;
     use celma_v0_core::parser::satisfy::Satisfy;
     use celma_v0_core::parser::specs::{Combine, Parse};
@@ -46,11 +49,7 @@ mod tests_transpiler {
     where
         S: Stream<Item = Token>,
     {
-        Satisfy::new((), |v, _| match v {
-            Token::Int(_) => true,
-            _ => false,
-        })
-        .map(|v| match v {
+        Satisfy::new((), |v, _| matches!(v, Token::Int(_))).map(|v| match v {
             Token::Int(i) => i,
             _ => panic!(),
         })
@@ -65,7 +64,7 @@ mod tests_transpiler {
             _ => false,
         })
         .map(|v| match v {
-            Token::Keyword(s) => s.clone(),
+            Token::Keyword(s) => s,
             _ => panic!(),
         })
     }
@@ -95,7 +94,7 @@ mod tests_transpiler {
     // Functions dedicated to parsers
     // ---------------------------------------------------------------------------------------------
 
-    #[derive(Clone)]
+    #[derive(Clone, Eq, PartialEq)]
     pub enum Operator {
         Plus,
         Mult,
@@ -105,12 +104,17 @@ mod tests_transpiler {
         a.into_iter().collect::<String>()
     }
 
-    fn mk_operation(l: Expr, r: Option<(Operator, Expr)>) -> Expr {
-        match r {
-            None => l,
-            Some((Operator::Plus, r)) => Expr::Plus(Box::new(l), Box::new(r)),
-            Some((Operator::Mult, r)) => Expr::Mult(Box::new(l), Box::new(r)),
-        }
+    fn expr<'a, S: 'a>() -> impl Parse<Expr, S> + Combine<Expr> + 'a
+    where
+        S: Stream<Item = Token>,
+    {
+        pratt(sexpr(), op())
+            .infix_left(Operator::Plus, 1, |l, _, r| {
+                Expr::Plus(Box::new(l), Box::new(r))
+            })
+            .infix_left(Operator::Mult, 2, |l, _, r| {
+                Expr::Mult(Box::new(l), Box::new(r))
+            })
     }
 
     // ---------------------------------------------------------------------------------------------
@@ -141,7 +145,6 @@ mod tests_transpiler {
 
     // Parser
     parsec_rules!(
-        let expr{Token}:{Expr}   = (s=sexpr e=(_=op _=expr)?) -> {mk_operation(s,e)}
         let op{Token}:{Operator} = (PLUS                      -> { Operator::Plus })
                                  | (MULT                      -> { Operator::Mult })
         let sexpr{Token}:{Expr}  = (LPAREN _=expr RPAREN)
@@ -158,7 +161,19 @@ mod tests_transpiler {
         let response = expr().and_left(eos()).parse(stream);
 
         match response {
-            Response::Success(v, _, _) => assert_eq!(v.eval(), 3),
+            Response::Success(v, _, _, _) => assert_eq!(v.eval(), 3),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_expr_with_precedence() {
+        let tokenizer = token();
+        let stream = ParserStream::new(&tokenizer, CharStream::new("2 * 3 + 1 * (2 + 2)"));
+        let response = expr().and_left(eos()).parse(stream);
+
+        match response {
+            Response::Success(v, _, _, _) => assert_eq!(v.eval(), 10),
             _ => assert_eq!(true, false),
         }
     }
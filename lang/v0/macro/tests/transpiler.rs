@@ -14,6 +14,8 @@
    limitations under the License.
 */
 
+#![allow(clippy::bool_assert_comparison)]
+
 #[cfg(test)]
 mod tests_transpiler {
     use celma_v0_core::parser::and::AndOperation;
@@ -30,7 +32,7 @@ mod tests_transpiler {
         let response = a.and_left(eos()).parse(CharStream::new("aaa"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v.len(), 3),
+            Success(v, _, _, _) => assert_eq!(v.len(), 3),
             _ => assert_eq!(true, false),
         }
     }
@@ -43,7 +45,7 @@ mod tests_transpiler {
         let response = b.and_left(eos()).parse(CharStream::new("baaa"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v.len(), 3),
+            Success(v, _, _, _) => assert_eq!(v.len(), 3),
             _ => assert_eq!(true, false),
         }
     }
@@ -55,7 +57,7 @@ mod tests_transpiler {
         let response = tf.and_left(eos()).parse(CharStream::new("true"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v, true),
+            Success(v, _, _, _) => assert_eq!(v, true),
             _ => assert_eq!(true, false),
         }
     }
@@ -67,7 +69,35 @@ mod tests_transpiler {
         let response = tf.and_left(eos()).parse(CharStream::new("false"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v, false),
+            Success(v, _, _, _) => assert_eq!(v, false),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_unicode_rust_identifier() {
+        let ident = parsec!(
+            (c=([:xid_start:]|'_') l=[:xid_continue:]*) -> { std::iter::once(c).chain(l).collect::<String>() }
+        );
+
+        let response = ident.and_left(eos()).parse(CharStream::new("变量_é"));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, "变量_é"),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_javascript_identifier() {
+        let ident = parsec!(
+            (c=([:xid_start:]|'$'|'_') l=([:xid_continue:]|'$')*) -> { std::iter::once(c).chain(l).collect::<String>() }
+        );
+
+        let response = ident.and_left(eos()).parse(CharStream::new("$élan"));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, "$élan"),
             _ => assert_eq!(true, false),
         }
     }
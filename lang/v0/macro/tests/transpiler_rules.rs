@@ -14,13 +14,22 @@
    limitations under the License.
 */
 
+#![allow(clippy::bool_assert_comparison)]
+
 #[cfg(test)]
 mod tests_transpiler {
     use celma_v0_core::parser::and::AndOperation;
+    use celma_v0_core::parser::char::{a_char, alpha, digit};
     use celma_v0_core::parser::core::eos;
-    use celma_v0_core::parser::response::Response::Success;
+    use celma_v0_core::parser::recovery::{RecoveryOperation, skip_until};
+    use celma_v0_core::parser::repeat::RepeatOperation;
+    use celma_v0_core::parser::response::Response::{Reject, Success};
     use celma_v0_core::parser::specs::Parse;
+    use celma_v0_core::parser::till::take_until;
     use celma_v0_core::stream::char_stream::CharStream;
+    use celma_v0_core::stream::iterator_stream::IteratorStream;
+    use celma_v0_core::stream::memo_stream::MemoStream;
+    use celma_v0_core::stream::recovery_stream::RecoveryStream;
     use celma_v0_macro::parsec_rules;
 
     #[test]
@@ -32,7 +41,7 @@ mod tests_transpiler {
         let response = a().and_left(eos()).parse(CharStream::new("aaa"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v.len(), 3),
+            Success(v, _, _, _) => assert_eq!(v.len(), 3),
             _ => assert_eq!(true, false),
         }
     }
@@ -47,7 +56,7 @@ mod tests_transpiler {
         let response = b().and_left(eos()).parse(CharStream::new("baaa"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v.len(), 3),
+            Success(v, _, _, _) => assert_eq!(v.len(), 3),
             _ => assert_eq!(true, false),
         }
     }
@@ -62,7 +71,7 @@ mod tests_transpiler {
         let response = tf().and_left(eos()).parse(CharStream::new("true"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v, true),
+            Success(v, _, _, _) => assert_eq!(v, true),
             _ => assert_eq!(true, false),
         }
     }
@@ -77,7 +86,7 @@ mod tests_transpiler {
         let response = tf().and_left(eos()).parse(CharStream::new("false"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v, false),
+            Success(v, _, _, _) => assert_eq!(v, false),
             _ => assert_eq!(true, false),
         }
     }
@@ -92,7 +101,7 @@ mod tests_transpiler {
         let response = ib().and_left(eos()).parse(CharStream::new("1,true"));
 
         match response {
-            Success(v, _, _) => assert_eq!(v, (true, 1)),
+            Success(v, _, _, _) => assert_eq!(v, (true, 1)),
             _ => assert_eq!(true, false),
         }
     }
@@ -100,7 +109,7 @@ mod tests_transpiler {
     #[test]
     fn it_parse_with_recursive_parser() {
         parsec_rules!(
-            let parens = ('(' parens ')')? -> { () }
+            let parens = ('(' parens ')')? -> {}
         );
 
         let response = parens()
@@ -108,7 +117,25 @@ mod tests_transpiler {
             .parse(CharStream::new("((((((((()))))))))"));
 
         match response {
-            Success(_, _, _) => assert_eq!(true, true),
+            Success(_, _, _, _) => assert_eq!(true, true),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_a_code_block_calling_a_sibling_rule() {
+        parsec_rules!(
+            let line:{Vec<char>} = {take_until(eol())}
+            let eol = ('\r'? '\n') -> {}
+        );
+
+        let response = line()
+            .and_left(eol())
+            .and_left(eos())
+            .parse(CharStream::new("abc\r\n"));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, vec!['a', 'b', 'c']),
             _ => assert_eq!(true, false),
         }
     }
@@ -122,7 +149,7 @@ mod tests_transpiler {
         let response = a().and_left(eos()).parse(CharStream::new("a"));
 
         match response {
-            Success(_, _, _) => assert_eq!(true, true),
+            Success(_, _, _, _) => assert_eq!(true, true),
             _ => assert_eq!(true, false),
         }
     }
@@ -136,7 +163,286 @@ mod tests_transpiler {
         let response = a().and_left(eos()).parse(CharStream::new(r#""\"""#));
 
         match response {
-            Success(_, _, _) => assert_eq!(true, true),
+            Success(_, _, _, _) => assert_eq!(true, true),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_reports_a_labelled_rule() {
+        parsec_rules!(
+            let ab:{char} = ('a' | 'b') @ "value"
+        );
+
+        let response = ab().and_left(eos()).parse(CharStream::new("c"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(e.to_string(), "expected value, found 'c'"),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_a_separated_rule() {
+        parsec_rules!(
+            let list:{Vec<char>} = '[' _=('a'|'b') % ',' ']'
+        );
+
+        let response = list().and_left(eos()).parse(CharStream::new("[a,b,a]"));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, vec!['a', 'b', 'a']),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_a_bounded_rule() {
+        parsec_rules!(
+            let hex:{Vec<char>} = "\\u" _=('0'|'1'|'a'|'f'){4}
+        );
+
+        let response = hex().and_left(eos()).parse(CharStream::new("\\u0a1f"));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, vec!['0', 'a', '1', 'f']),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_a_rule_with_skipped_repetitions() {
+        parsec_rules!(
+            let item:{char} = ' '* _=('a'|'b') ' '*
+        );
+
+        let response = item().and_left(eos()).parse(CharStream::new("  b   "));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, 'b'),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_a_keyword_rule() {
+        parsec_rules!(
+            let keyword:{()} = !("let" ~(alpha|digit|'_')) -> {}
+            let ident:{String} = c=#(alpha+) -> { c.into_iter().collect() }
+            let token:{Option<String>} = (keyword -> { None }) | (i=ident -> { Some(i) })
+        );
+
+        let response = token().and_left(eos()).parse(CharStream::new("letter"));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, Some(String::from("letter"))),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_a_memo_rule() {
+        parsec_rules!(
+            @memo let value:{String} = c=#(digit+) -> { c.into_iter().collect() }
+            let pair:{(String, char)} = !((v=value s=',') -> { (v, s) }) | ((v=value s=';') -> { (v, s) })
+        );
+
+        let response = pair()
+            .and_left(eos())
+            .parse(MemoStream::new(CharStream::new("123;")));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, (String::from("123"), ';')),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_a_left_recursive_rule() {
+        parsec_rules!(
+            @left_rec let expr:{i64} = ((l=expr '-' r=term) -> { l - r }) | term
+            let term:{i64} = c=digit -> { c.to_digit(10).unwrap() as i64 }
+        );
+
+        let response = expr()
+            .and_left(eos())
+            .parse(MemoStream::new(CharStream::new("8-2-1")));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, 5),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_left_recursive_rules() {
+        parsec_rules!(
+            @left_rec let expr:{i64} = ((l=expr '-' r=term) -> { l - r }) | term
+            @left_rec let term:{i64} = ((l=term '*' r=value) -> { l * r }) | value
+            let value:{i64} = c=digit -> { c.to_digit(10).unwrap() as i64 }
+        );
+
+        let response = expr()
+            .and_left(eos())
+            .parse(MemoStream::new(CharStream::new("8-2*3-1")));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, 1),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_a_memo_recognizing_rule() {
+        parsec_rules!(
+            @memo let word:{&'a str} = $(alpha+)
+            let pair:{(&'a str, char)} = !((v=word s=',') -> { (v, s) }) | ((v=word s=';') -> { (v, s) })
+        );
+
+        let response = pair()
+            .and_left(eos())
+            .parse(MemoStream::new(CharStream::new("abc;")));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, ("abc", ';')),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_a_left_recursive_recognizing_rule() {
+        parsec_rules!(
+            @left_rec let sum:{&'a str} = $((sum '+' digit) | digit)
+        );
+
+        let response = sum()
+            .and_left(eos())
+            .parse(MemoStream::new(CharStream::new("1+2+3")));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, "1+2+3"),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_parse_a_chained_rule() {
+        parsec_rules!(
+            let expr:{i64} = term %< (('+' -> { (|a, b| a + b) as fn(i64, i64) -> i64 })
+                                    | ('-' -> { (|a, b| a - b) as fn(i64, i64) -> i64 }))
+            let term:{i64} = c=('0'|'1'|'2'|'8') -> { c.to_digit(10).unwrap() as i64 }
+        );
+
+        let response = expr().and_left(eos()).parse(CharStream::new("8-2-1+0"));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, 5),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_commits_a_cut_rule() {
+        parsec_rules!(
+            let entry:{char} = ('a'? => v='b' -> { v }) | 'c'
+        );
+
+        let response = entry().and_left(eos()).parse(CharStream::new("c"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(e.to_string(), "expected 'b', found 'c'"),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_recovers_a_rule() {
+        parsec_rules!(
+            let item:{char} = 'a' | 'b'
+        );
+
+        let response = item()
+            .recover_with(skip_until(a_char(';')))
+            .and_left(a_char(';'))
+            .rep()
+            .and_left(eos())
+            .parse(RecoveryStream::new(CharStream::new("a;cd;b;")));
+
+        match response {
+            Success(v, s, _, _) => {
+                assert_eq!(v, vec![Some('a'), None, Some('b')]);
+                assert_eq!(s.errors().len(), 1);
+                assert_eq!(s.errors()[0].to_string(), "expected 'a' or 'b', found 'c'");
+            }
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_recognize_identifiers() {
+        parsec_rules!(
+            let ident:{&'a str} = $([:xid_start:] [:xid_continue:]*)
+            let idents:{Vec<&'a str>} = ident+ % ' '
+        );
+
+        let response = idents().and_left(eos()).parse(CharStream::new("café 变量"));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, vec!["café", "变量"]),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_does_not_bound_other_rules_with_slices() {
+        parsec_rules!(
+            let word:{&'a str} = $letters
+            let letters:{Vec<char>} = alpha+
+        );
+
+        let response = letters()
+            .and_left(eos())
+            .parse(IteratorStream::new("abc".chars()));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, vec!['a', 'b', 'c']),
+            _ => assert_eq!(true, false),
+        }
+
+        let response = word().and_left(eos()).parse(CharStream::new("abc"));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, "abc"),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_transform_a_recognized_slice() {
+        parsec_rules!(
+            let number:{u32} = c=$(digit+) -> { c.parse().unwrap() }
+        );
+
+        let response = number().and_left(eos()).parse(CharStream::new("123"));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, 123),
+            _ => assert_eq!(true, false),
+        }
+    }
+
+    #[test]
+    fn it_check_items_of_any_stream() {
+        parsec_rules!(
+            let word:{String} = c=#(alpha+) -> { c.into_iter().collect() }
+        );
+
+        let response = word()
+            .and_left(eos())
+            .parse(IteratorStream::new("abc".chars()));
+
+        match response {
+            Success(v, _, _, _) => assert_eq!(v, "abc"),
             _ => assert_eq!(true, false),
         }
     }
@@ -16,10 +16,13 @@
 use celma_v0_core::parser::a_try::a_try;
 use celma_v0_core::parser::and::{AndOperation, AndProjection};
 use celma_v0_core::parser::bind::BindOperation;
-use celma_v0_core::parser::char::{a_char, char_in_range, char_in_set, not_char};
+use celma_v0_core::parser::char::{a_char, char_in_range, char_in_set, digit, not_char};
 use celma_v0_core::parser::core::{eos, fail, parser, returns};
+use celma_v0_core::parser::cut::CutOperation;
+use celma_v0_core::parser::label::LabelOperation;
 use celma_v0_core::parser::lazy::lazy;
 use celma_v0_core::parser::literal::{delimited_char, delimited_string, string};
+use celma_v0_core::parser::lookahead::lookahead;
 use celma_v0_core::parser::map::MapOperation;
 use celma_v0_core::parser::option::OptionalOperation;
 use celma_v0_core::parser::or::OrOperation;
@@ -29,11 +32,18 @@ use celma_v0_core::stream::specs::Stream;
 use std::ops::Range;
 
 use celma_v0_ast::syntax::ASTParsec::{
-    PAtom, PAtoms, PBind, PCheck, PChoice, PCode, PEpsilon, PIdent, PMap, PNot, POptional, PRepeat,
-    PSequence, PTry,
+    PAtom, PAtoms, PBind, PBounded, PChain, PCheck, PChoice, PClass, PCode, PCut, PEpsilon, PIdent,
+    PLabel, PLookahead, PMap, PNot, POptional, PRecognize, PRepeat, PSeparated, PSequence, PTry,
 };
 use celma_v0_ast::syntax::{ASTParsec, ASTParsecRule};
 
+#[derive(Clone)]
+enum Occurrence {
+    Optional,
+    Repeat(bool),
+    Bounded(usize, Option<usize>),
+}
+
 #[inline]
 fn skip<'a, S>() -> impl Parse<(), S> + Combine<()> + 'a
 where
@@ -75,14 +85,25 @@ where
 
 // -------------------------------------------------------------------------------------------------
 
+type RuleComponents = (
+    ((((bool, bool), String), Option<String>), Option<String>),
+    ASTParsec,
+);
+
 #[inline]
 fn parsec_rules<'a, S>() -> impl Parse<Vec<ASTParsecRule>, S> + Combine<Vec<ASTParsecRule>> + 'a
 where
     S: Stream<Item = char> + 'a,
 {
-    string("let")
+    string("@memo")
+        .map(|_| (true, false))
+        .or(string("@left_rec").map(|_| (true, true)))
         .and_left(skip())
-        .and_right(ident())
+        .opt()
+        .map(|m| m.unwrap_or((false, false)))
+        .and_left(string("let"))
+        .and_left(skip())
+        .and(ident())
         .and_left(skip())
         .and(kind().opt())
         .and_left(skip())
@@ -92,16 +113,14 @@ where
         .and_left(skip())
         .and(parsec())
         .and_left(skip())
-        .map(
-            |(((n, i), r), b): (((String, Option<String>), Option<String>), ASTParsec)| {
-                ASTParsecRule {
-                    name: n,
-                    input: i.unwrap_or(String::from("char")),
-                    returns: r.unwrap_or(String::from("()")),
-                    rule: b,
-                }
-            },
-        )
+        .map(|(((((m, l), n), i), r), b): RuleComponents| ASTParsecRule {
+            memo: m,
+            left_recursive: l,
+            name: n,
+            input: i.unwrap_or(String::from("char")),
+            returns: r.unwrap_or(String::from("()")),
+            rule: b,
+        })
         .rep()
 }
 
@@ -119,34 +138,67 @@ where
         .and_left(skip())
         .and(occurrence().opt())
         .and_left(skip())
+        .and(separator().opt())
+        .bind(|v| match v {
+            ((_, Some(Occurrence::Bounded(_, _))), Some((None, _))) => {
+                parser(fail(false).label("unbounded separated repetition").cut())
+            }
+            v => parser(returns(v)),
+        })
+        .and_left(skip())
+        .and(label().opt())
+        .and_left(skip())
         .and(additional().opt())
         .and_left(skip())
         .and(transform().opt())
-        .map(|((((bind, atom), occ), add), trans)| {
-            let occ = if let Some(value) = occ {
+        .map(|((((((bind, atom), occ), sep), label), add), trans)| {
+            let (sep, chain) = match sep {
+                Some((None, sep)) => (Some(sep), None),
+                Some((Some(left), op)) => (None, Some((left, op))),
+                None => (None, None),
+            };
+
+            let occ = if let Some(sep) = sep {
+                match occ {
+                    Some(Occurrence::Repeat(false)) => PSeparated(false, atom.wrap(), sep.wrap()),
+                    Some(Occurrence::Optional) => {
+                        POptional(PSeparated(false, atom.wrap(), sep.wrap()).wrap())
+                    }
+                    _ => PSeparated(true, atom.wrap(), sep.wrap()),
+                }
+            } else if let Some(value) = occ {
                 match value {
-                    '?' => POptional(atom.wrap()),
-                    '*' => PRepeat(true, atom.wrap()),
-                    '+' => PRepeat(false, atom.wrap()),
-                    _ => atom,
+                    Occurrence::Optional => POptional(atom.wrap()),
+                    Occurrence::Repeat(b) => PRepeat(b, atom.wrap()),
+                    Occurrence::Bounded(n, m) => PBounded(n, m, atom.wrap()),
                 }
             } else {
                 atom
             };
 
+            let occ = if let Some((left, op)) = chain {
+                PChain(left, occ.wrap(), op.wrap())
+            } else {
+                occ
+            };
+
+            let occ = if let Some(value) = label {
+                PLabel(occ.wrap(), value)
+            } else {
+                occ
+            };
+
             let bind = if let Some(value) = bind {
                 PBind(value, occ.wrap())
             } else {
                 occ
             };
 
-            let add = if add.is_some() {
-                let value = add.unwrap();
-
-                if value.0 {
-                    PChoice(bind.wrap(), value.1.wrap())
-                } else {
-                    PSequence(bind.wrap(), value.1.wrap())
+            let add = if let Some(value) = add {
+                match value.0 {
+                    '|' => PChoice(bind.wrap(), value.1.wrap()),
+                    '=' => PSequence(bind.wrap(), PCut(value.1.wrap()).wrap()),
+                    _ => PSequence(bind.wrap(), value.1.wrap()),
                 }
             } else {
                 bind
@@ -165,24 +217,102 @@ fn binding<'a, S>() -> impl Parse<String, S> + Combine<String> + 'a
 where
     S: Stream<Item = char> + 'a,
 {
-    ident().and_left(skip()).and_left(a_char('='))
+    ident()
+        .and_left(skip())
+        .and_left(a_char('='))
+        .and_left(lookahead(not_char('>')))
 }
 
 #[inline]
-fn occurrence<'a, S>() -> impl Parse<char, S> + Combine<char> + 'a
+fn occurrence<'a, S>() -> impl Parse<Occurrence, S> + Combine<Occurrence> + 'a
 where
     S: Stream<Item = char> + 'a,
 {
     char_in_set(vec!['+', '?', '*'])
+        .map(|c| match c {
+            '?' => Occurrence::Optional,
+            '*' => Occurrence::Repeat(true),
+            _ => Occurrence::Repeat(false),
+        })
+        .or(a_try(bounds()).bind(|bounds| match bounds {
+            Some((n, Some(m))) if n > m => parser(fail(false).label("ordered bounds").cut()),
+            Some((n, m)) => parser(returns(Occurrence::Bounded(n, m))),
+            None => parser(fail(false).label("bounds within usize").cut()),
+        }))
 }
 
-fn additional<'a, S>() -> impl Parse<(bool, ASTParsec), S> + Combine<(bool, ASTParsec)> + 'a
+type Bounds = (usize, Option<usize>);
+
+// Bounds are None when a number does not fit in usize.
+#[inline]
+fn bounds<'a, S>() -> impl Parse<Option<Bounds>, S> + Combine<Option<Bounds>> + 'a
+where
+    S: Stream<Item = char> + 'a,
+{
+    a_char('{')
+        .and_left(skip())
+        .and_right(number())
+        .and_left(skip())
+        .and(
+            a_char(',')
+                .and_left(skip())
+                .and_right(number().opt())
+                .and_left(skip())
+                .opt(),
+        )
+        .and_left(a_char('}'))
+        .map(|(n, m)| match (n, m) {
+            (Some(n), None) => Some((n, Some(n))),
+            (Some(n), Some(None)) => Some((n, None)),
+            (Some(n), Some(Some(Some(m)))) => Some((n, Some(m))),
+            _ => None,
+        })
+}
+
+#[inline]
+fn number<'a, S>() -> impl Parse<Option<usize>, S> + Combine<Option<usize>> + 'a
+where
+    S: Stream<Item = char> + 'a,
+{
+    digit()
+        .rep()
+        .map(|v| v.into_iter().collect::<String>().parse().ok())
+}
+
+#[inline]
+fn separator<'a, S>()
+-> impl Parse<(Option<bool>, ASTParsec), S> + Combine<(Option<bool>, ASTParsec)> + 'a
+where
+    S: Stream<Item = char> + 'a,
+{
+    a_char('%')
+        .and_right(char_in_set(vec!['<', '>']).opt())
+        .map(|c| c.map(|c| c == '<'))
+        .and_left(skip())
+        .and(atom())
+}
+
+#[inline]
+fn label<'a, S>() -> impl Parse<String, S> + Combine<String> + 'a
+where
+    S: Stream<Item = char> + 'a,
+{
+    a_try(
+        a_char('@')
+            .and_left(skip())
+            .and_left(lookahead(a_char('"'))),
+    )
+    .and_right(delimited_string())
+}
+
+fn additional<'a, S>() -> impl Parse<(char, ASTParsec), S> + Combine<(char, ASTParsec)> + 'a
 where
     S: Stream<Item = char> + 'a,
 {
     a_char('|')
+        .or(string("=>").map(|_| '='))
         .opt()
-        .map(|o| o.is_some())
+        .map(|o| o.unwrap_or(' '))
         .and_left(skip())
         .and(lazy(|| parser(parsec())))
 }
@@ -204,6 +334,18 @@ where
             .and_left(skip())
             .and_right(atom2())
             .map(|p| PCheck(p.wrap())))
+        .or(a_char('$')
+            .and_left(skip())
+            .and_right(atom2())
+            .map(|p| PRecognize(p.wrap())))
+        .or(a_char('&')
+            .and_left(skip())
+            .and_right(atom2())
+            .map(|p| PLookahead(true, p.wrap())))
+        .or(a_char('~')
+            .and_left(skip())
+            .and_right(atom2())
+            .map(|p| PLookahead(false, p.wrap())))
         .or(atom2())
 }
 
@@ -221,9 +363,26 @@ where
         .or(code().map(PCode))
         .or(delimited_char().map(PAtom))
         .or(delimited_string().map(|l| PAtoms(l.chars().collect())))
+        .or(class().map(PClass))
         .or(ident().map(PIdent))
 }
 
+#[inline]
+fn class<'a, S>() -> impl Parse<String, S> + Combine<String> + 'a
+where
+    S: Stream<Item = char> + 'a,
+{
+    a_char('[')
+        .and_left(skip())
+        .and_left(a_char(':'))
+        .and_left(skip())
+        .and_right(ident())
+        .and_left(skip())
+        .and_left(a_char(':'))
+        .and_left(skip())
+        .and_left(a_char(']'))
+}
+
 #[inline]
 fn transform<'a, S>() -> impl Parse<String, S> + Combine<String> + 'a
 where
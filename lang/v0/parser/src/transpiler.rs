@@ -17,11 +17,12 @@
 extern crate proc_macro;
 
 use celma_v0_ast::syntax::ASTParsec::{
-    PAtom, PAtoms, PBind, PCheck, PChoice, PCode, PEpsilon, PIdent, PMap, PNot, POptional, PRepeat,
-    PSequence, PTry,
+    PAtom, PAtoms, PBind, PBounded, PChain, PCheck, PChoice, PClass, PCode, PCut, PEpsilon, PIdent,
+    PLabel, PLookahead, PMap, PNot, POptional, PRecognize, PRepeat, PSeparated, PSequence, PTry,
 };
 use celma_v0_ast::syntax::{ASTParsec, ASTParsecRule};
-use proc_macro2::{Span, TokenStream};
+use celma_v0_core::parser::char::is_category;
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
 use quote::quote;
 use syn::Error;
 
@@ -31,37 +32,291 @@ pub trait Transpile<E> {
 
 impl Transpile<TokenStream> for Vec<ASTParsecRule> {
     fn transpile(&self) -> Result<TokenStream, Error> {
-        let parsers: TokenStream = self
+        let input = match self.first() {
+            Some(rule) if self.iter().all(|r| r.input == rule.input) => &rule.input,
+            _ => {
+                let parsers: TokenStream =
+                    self.iter()
+                        .map(|a| a.transpile())
+                        .collect::<Result<TokenStream, Error>>()?;
+
+                return Ok(quote!(
+                    #parsers
+                ));
+            }
+        };
+
+        // Rules recognizing slices, directly or through a sibling, only accept streams borrowing
+        // their input: they are built apart so that the other rules accept any stream.
+        let slices = slice_rules(self);
+
+        if !slices.is_empty() && slices.len() < self.len() {
+            let (slice, stream): (Vec<_>, Vec<_>) =
+                self.iter().cloned().partition(|r| slices.contains(&r.name));
+            let slice: TokenStream = slice.transpile()?;
+            let stream: TokenStream = stream.transpile()?;
+
+            return Ok(quote!(
+                #stream
+                #slice
+            ));
+        }
+
+        // Rules of the same input are built once and reference each other through
+        // the shared cells of a recursive builder instead of being rebuilt on each entry.
+        let names = self.iter().map(|r| r.name.clone()).collect::<Vec<_>>();
+        let builder = syn::Ident::new(&format!("__celma_rules_{}", names[0]), Span::call_site());
+        let locals = names.iter().map(|n| local_name(n)).collect::<Vec<_>>();
+        let bound = stream_bound(input, !slices.is_empty())?;
+        let returns = self
+            .iter()
+            .map(|r| syn::parse_str::<TokenStream>(r.returns.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let bodies = self
             .iter()
-            .map(|a| a.transpile())
-            .collect::<Result<TokenStream, Error>>()?;
+            .map(|r| {
+                let rule = ASTParsecRule {
+                    rule: reference_rules(&r.rule, &names),
+                    ..r.clone()
+                };
+                transpile_rule(&rule)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let parsers = self
+            .iter()
+            .enumerate()
+            .map(|(i, r)| {
+                let name = syn::Ident::new(r.name.as_str(), Span::call_site());
+                let local = &locals[i];
+                let others = locals.iter().enumerate().filter(|(j, _)| *j != i).map(|(_, l)| l);
+                let returns = &returns[i];
+
+                quote!(
+                    pub fn #name<'a,S:'a>() -> impl celma_v0_core::parser::specs::Parse<#returns,S> +
+                                                    celma_v0_core::parser::specs::Combine<#returns> +
+                                                    'a
+                        where S:#bound,
+                    {
+                        let (#(#locals,)*) = #builder();
+                        #local #(.hold(#others))*
+                    }
+                )
+            })
+            .collect::<TokenStream>();
 
         Ok(quote!(
+            #[allow(non_snake_case)]
+            fn #builder<'a,S:'a>() -> (#(celma_v0_core::parser::recursive::Recursive<'a,#returns,S>,)*)
+                where S:#bound,
+            {
+                use celma_v0_core::parser::a_try::a_try;
+                use celma_v0_core::parser::and::AndOperation;
+                use celma_v0_core::parser::check::check;
+                use celma_v0_core::parser::lookahead::lookahead;
+                use celma_v0_core::parser::map::MapOperation;
+                use celma_v0_core::parser::not::NotOperation;
+                use celma_v0_core::parser::option::OptionalOperation;
+                use celma_v0_core::parser::or::OrOperation;
+                use celma_v0_core::parser::repeat::RepeatOperation;
+                use celma_v0_core::parser::specs::Parse;
+
+                #(let #locals = celma_v0_core::parser::recursive::Recursive::new();)*
+                #(#locals.define(#bodies);)*
+                (#(#locals,)*)
+            }
+
             #parsers
         ))
     }
 }
 
+fn local_name(name: &str) -> syn::Ident {
+    syn::Ident::new(&format!("rule_{name}"), Span::call_site())
+}
+
+fn reference_rules(ast: &ASTParsec, names: &[String]) -> ASTParsec {
+    let wrap = |p: &ASTParsec| reference_rules(p, names).wrap();
+
+    match ast {
+        PIdent(n) if names.contains(n) => PCode(format!("{}.reference()", local_name(n))),
+        PBind(n, p) => PBind(n.clone(), wrap(p)),
+        PMap(p, c) => PMap(wrap(p), c.clone()),
+        PSequence(l, r) => PSequence(wrap(l), wrap(r)),
+        PChoice(l, r) => PChoice(wrap(l), wrap(r)),
+        PNot(p) => PNot(wrap(p)),
+        PTry(p) => PTry(wrap(p)),
+        PCheck(p) => PCheck(wrap(p)),
+        PRecognize(p) => PRecognize(wrap(p)),
+        PLookahead(b, p) => PLookahead(*b, wrap(p)),
+        POptional(p) => POptional(wrap(p)),
+        PRepeat(b, p) => PRepeat(*b, wrap(p)),
+        PBounded(n, m, p) => PBounded(*n, *m, wrap(p)),
+        PSeparated(b, p, s) => PSeparated(*b, wrap(p), wrap(s)),
+        PChain(b, p, o) => PChain(*b, wrap(p), wrap(o)),
+        PLabel(p, l) => PLabel(wrap(p), l.clone()),
+        PCut(p) => PCut(wrap(p)),
+        PCode(c) => match syn::parse_str::<TokenStream>(c) {
+            Ok(code) => PCode(reference_calls(code, names).to_string()),
+            Err(_) => ast.clone(),
+        },
+        PEpsilon() | PIdent(_) | PAtom(_) | PAtoms(_) | PClass(_) => ast.clone(),
+    }
+}
+
+// Sibling rules called in a code block, i.e. `{take_until(EOL())}`, are also
+// referenced through their cells: calling them would build the block again.
+fn reference_calls(code: TokenStream, names: &[String]) -> TokenStream {
+    let mut tokens = code.into_iter().peekable();
+    let mut result = Vec::new();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ref n) if names.contains(&n.to_string()) && !qualified(&result) => {
+                match tokens.peek() {
+                    Some(TokenTree::Group(g))
+                        if g.delimiter() == Delimiter::Parenthesis && g.stream().is_empty() =>
+                    {
+                        let local = local_name(&n.to_string());
+                        tokens.next();
+                        result.extend(quote!(#local.reference()));
+                    }
+                    _ => result.push(token),
+                }
+            }
+            TokenTree::Group(g) => {
+                let mut group = Group::new(g.delimiter(), reference_calls(g.stream(), names));
+                group.set_span(g.span());
+                result.push(TokenTree::Group(group));
+            }
+            _ => result.push(token),
+        }
+    }
+
+    result.into_iter().collect()
+}
+
+fn qualified(tokens: &[TokenTree]) -> bool {
+    matches!(tokens.last(), Some(TokenTree::Punct(p)) if p.as_char() == '.' || p.as_char() == ':')
+}
+
+fn slice_rules(rules: &[ASTParsecRule]) -> Vec<String> {
+    let mut slices = Vec::new();
+
+    loop {
+        let more = rules
+            .iter()
+            .filter(|r| !slices.contains(&r.name) && recognizes(&r.rule, &slices))
+            .map(|r| r.name.clone())
+            .collect::<Vec<_>>();
+
+        if more.is_empty() {
+            return slices;
+        }
+
+        slices.extend(more);
+    }
+}
+
+fn recognizes(ast: &ASTParsec, slices: &[String]) -> bool {
+    let recognizes = |p: &ASTParsec| recognizes(p, slices);
+
+    match ast {
+        PRecognize(_) => true,
+        PIdent(n) => slices.contains(n),
+        PCode(c) => syn::parse_str::<TokenStream>(c).is_ok_and(|c| mentions(c, slices)),
+        PBind(_, p) | PMap(p, _) | PNot(p) | PTry(p) | PCheck(p) | PLookahead(_, p) => {
+            recognizes(p)
+        }
+        POptional(p) | PRepeat(_, p) | PBounded(_, _, p) | PLabel(p, _) | PCut(p) => recognizes(p),
+        PSequence(l, r) | PChoice(l, r) | PSeparated(_, l, r) | PChain(_, l, r) => {
+            recognizes(l) || recognizes(r)
+        }
+        PEpsilon() | PAtom(_) | PAtoms(_) | PClass(_) => false,
+    }
+}
+
+fn mentions(code: TokenStream, names: &[String]) -> bool {
+    code.into_iter().any(|token| match token {
+        TokenTree::Ident(n) => names.contains(&n.to_string()),
+        TokenTree::Group(g) => mentions(g.stream(), names),
+        _ => false,
+    })
+}
+
+// Rules capturing slices with `$` only accept streams borrowing the parsed input.
+fn stream_bound(input: &str, slice: bool) -> Result<TokenStream, Error> {
+    let item = syn::parse_str::<TokenStream>(input)?;
+
+    if !slice {
+        Ok(quote!(celma_v0_core::stream::specs::Stream<Item=#item>))
+    } else if input == "char" {
+        Ok(quote!(celma_v0_core::stream::specs::Slice<Item=#item, Slice=&'a str>))
+    } else {
+        Ok(quote!(celma_v0_core::stream::specs::Slice<Item=#item, Slice=&'a [#item]>))
+    }
+}
+
+fn transpile_rule(rule: &ASTParsecRule) -> Result<TokenStream, Error> {
+    let ASTParsecRule {
+        memo,
+        left_recursive,
+        rule: body,
+        ..
+    } = rule;
+
+    // Recognized slices borrow the input and cannot be memoized: the recognized parser is.
+    if let (true, PRecognize(p)) = (*memo || *left_recursive, body) {
+        let (_, pt) = transpile_body_in(p, Some(&quote!(S)))?;
+        let body = memoize(*left_recursive, quote!(#pt.map(|_| ())));
+
+        return Ok(quote!(celma_v0_core::parser::recognize::recognize::<_, _, S>(#body)));
+    }
+
+    let body = transpile_body_in(body, Some(&quote!(S)))?.1;
+
+    if *memo || *left_recursive {
+        Ok(memoize(*left_recursive, body))
+    } else {
+        Ok(body)
+    }
+}
+
+fn memoize(left_recursive: bool, body: TokenStream) -> TokenStream {
+    if left_recursive {
+        quote!({
+            static MEMO: celma_v0_core::parser::memo::MemoKey =
+                celma_v0_core::parser::memo::MemoKey::new();
+            MEMO.left_rec(#body)
+        })
+    } else {
+        quote!({
+            static MEMO: celma_v0_core::parser::memo::MemoKey =
+                celma_v0_core::parser::memo::MemoKey::new();
+            MEMO.memo(#body)
+        })
+    }
+}
+
 impl Transpile<TokenStream> for ASTParsecRule {
     fn transpile(&self) -> Result<TokenStream, Error> {
         let Self {
             name,
             input,
             returns,
-            rule: body,
             ..
         } = self;
 
         let name = syn::Ident::new(name.as_str(), Span::call_site());
-        let input = syn::parse_str::<TokenStream>(input.as_str())?;
+        let bound = stream_bound(input, recognizes(&self.rule, &[]))?;
         let returns = syn::parse_str::<TokenStream>(returns.as_str())?;
-        let body = body.transpile_body()?.1;
+        let body = transpile_rule(self)?;
 
         Ok(quote!(
             pub fn #name<'a,S:'a>() -> impl celma_v0_core::parser::specs::Parse<#returns,S> +
                                             celma_v0_core::parser::specs::Combine<#returns> +
                                             'a
-                where S:celma_v0_core::stream::specs::Stream<Item=#input>,
+                where S:#bound,
             {
                 use celma_v0_core::parser::a_try::a_try;
                 use celma_v0_core::parser::and::AndOperation;
@@ -108,77 +363,221 @@ pub trait TranspileBody<E> {
 
 impl TranspileBody<(Option<String>, TokenStream)> for ASTParsec {
     fn transpile_body(&self) -> Result<(Option<String>, TokenStream), Error> {
-        match self {
-            PEpsilon() => Ok((None, quote! { returns(()) })),
-            PBind(n, p) => Ok((Some(n.clone()), p.transpile_body()?.1)),
-            PIdent(n) => {
-                let n = syn::Ident::new(n, Span::call_site());
-                Ok((None, quote!(celma_v0_core::parser::lazy::lazy(|| #n()))))
-            }
-            PAtom(c) => Ok((None, quote!(celma_v0_core::parser::char::a_char(#c)))),
-            PAtoms(s) => {
-                let s = s.iter().collect::<String>();
-                Ok((None, quote!(celma_v0_core::parser::literal::string(#s))))
-            }
-            PCode(c) => {
-                let c = syn::parse_str::<TokenStream>(c.as_str()).unwrap();
-                Ok((None, quote!(#c)))
-            }
-            PMap(p, c) => {
-                let (pp, pt) = p.transpile_body()?;
-                let c = syn::parse_str::<TokenStream>(c.as_str())?;
-
-                if let Some(p) = pp {
-                    let pp = syn::parse_str::<TokenStream>(p.as_str())?;
-                    Ok((None, quote!(#pt.map(|#pp|{ #c }))))
-                } else {
-                    Ok((None, quote!(#pt.map(|_|{ #c }))))
-                }
+        transpile_body_in(self, None)
+    }
+}
+
+// Parsers of a rule body are given the stream `S` of the rule: the slices recognized with `$`
+// then have a known type in the transformations, i.e. `c=$(digit+) -> { c.parse().unwrap() }`.
+fn transpile_body_in(
+    ast: &ASTParsec,
+    stream: Option<&TokenStream>,
+) -> Result<(Option<String>, TokenStream), Error> {
+    match ast {
+        PEpsilon() => Ok((None, quote! { returns(()) })),
+        PBind(n, p) => Ok((Some(n.clone()), transpile_body_in(p, stream)?.1)),
+        PIdent(n) => {
+            let n = syn::Ident::new(n, Span::call_site());
+            Ok((None, quote!(celma_v0_core::parser::lazy::lazy(|| #n()))))
+        }
+        PAtom(c) => Ok((None, quote!(celma_v0_core::parser::char::a_char(#c)))),
+        PAtoms(s) => {
+            let s = s.iter().collect::<String>();
+            Ok((None, quote!(celma_v0_core::parser::literal::string(#s))))
+        }
+        PClass(n) => match n.as_str() {
+            "alpha" => Ok((None, quote!(celma_v0_core::parser::char::unicode_alpha()))),
+            "alnum" => Ok((
+                None,
+                quote!(celma_v0_core::parser::char::unicode_alphanumeric()),
+            )),
+            "space" => Ok((
+                None,
+                quote!(celma_v0_core::parser::char::unicode_whitespace()),
+            )),
+            "xid_start" => Ok((None, quote!(celma_v0_core::parser::char::xid_start()))),
+            "xid_continue" => Ok((None, quote!(celma_v0_core::parser::char::xid_continue()))),
+            _ if is_category(n) => Ok((None, quote!(celma_v0_core::parser::char::category(#n)))),
+            _ => Err(Error::new(
+                Span::call_site(),
+                format!("unknown character class [:{n}:]"),
+            )),
+        },
+        PCode(c) => {
+            let c = syn::parse_str::<TokenStream>(c.as_str()).unwrap();
+            Ok((None, quote!(#c)))
+        }
+        PMap(p, c) => {
+            let (pp, pt) = transpile_body_in(p, stream)?;
+            let c = syn::parse_str::<TokenStream>(c.as_str())?;
+
+            if let Some(p) = pp {
+                let pp = syn::parse_str::<TokenStream>(p.as_str())?;
+                Ok((None, quote!(#pt.map(|#pp|{ #c }))))
+            } else {
+                let pt = transpile_skip_in(p, stream)?;
+                Ok((None, quote!(#pt.map(|_|{ #c }))))
             }
-            PSequence(l, r) => {
-                let (lp, lt) = l.transpile_body()?;
-                let (rp, rt) = r.transpile_body()?;
+        }
+        PSequence(l, r) => {
+            let (lp, lt) = transpile_body_in(l, stream)?;
+            let (rp, rt) = transpile_body_in(r, stream)?;
 
-                if lp.is_none() {
+            match (lp, rp) {
+                (None, rp) => {
+                    let lt = transpile_skip_in(l, stream)?;
                     Ok((rp, quote!(#lt.and_right(#rt))))
-                } else if rp.is_none() {
+                }
+                (lp, None) => {
+                    let rt = transpile_skip_in(r, stream)?;
                     Ok((lp, quote!(#lt.and_left(#rt))))
-                } else {
-                    Ok((
-                        Some(format!("({},{})", lp.unwrap(), rp.unwrap())),
-                        quote!(#lt.and(#rt)),
-                    ))
+                }
+                (Some(lp), Some(rp)) => {
+                    Ok((Some(format!("({},{})", lp, rp)), quote!(#lt.and(#rt))))
                 }
             }
-            PChoice(l, r) => {
-                let (_, lt) = l.transpile_body()?;
-                let (_, rt) = r.transpile_body()?;
-                Ok((None, quote!(#lt.or(#rt))))
+        }
+        PChoice(l, r) => {
+            let (_, lt) = transpile_body_in(l, stream)?;
+            let (_, rt) = transpile_body_in(r, stream)?;
+            Ok((None, quote!(#lt.or(#rt))))
+        }
+        PNot(p) => {
+            let (_, pt) = transpile_body_in(p, stream)?;
+            Ok((None, quote!(#pt.not())))
+        }
+        PTry(p) => {
+            let (_, pt) = transpile_body_in(p, stream)?;
+            Ok((None, quote!(a_try(#pt))))
+        }
+        PCheck(p) => {
+            let (_, pt) = transpile_body_in(p, stream)?;
+            Ok((None, quote!(check(#pt))))
+        }
+        PRecognize(p) => {
+            let (_, pt) = transpile_body_in(p, stream)?;
+            let stream = stream.map(|s| quote!(::<_, _, #s>));
+            Ok((
+                None,
+                quote!(celma_v0_core::parser::recognize::recognize #stream (#pt)),
+            ))
+        }
+        PLookahead(b, p) => {
+            let (_, pt) = transpile_body_in(p, stream)?;
+            if *b {
+                Ok((
+                    None,
+                    quote!(celma_v0_core::parser::lookahead::followed_by(#pt)),
+                ))
+            } else {
+                Ok((
+                    None,
+                    quote!(celma_v0_core::parser::lookahead::not_followed_by(#pt)),
+                ))
             }
-            PNot(p) => {
-                let (_, pt) = p.transpile_body()?;
-                Ok((None, quote!(#pt.not())))
+        }
+        POptional(p) => {
+            let (_, pt) = transpile_body_in(p, stream)?;
+            Ok((None, quote!(#pt.opt())))
+        }
+        PRepeat(b, p) => {
+            let (_, pt) = transpile_body_in(p, stream)?;
+            if *b {
+                Ok((None, quote!(#pt.opt_rep())))
+            } else {
+                Ok((None, quote!(#pt.rep())))
             }
-            PTry(p) => {
-                let (_, pt) = p.transpile_body()?;
-                Ok((None, quote!(a_try(#pt))))
+        }
+        PBounded(n, m, p) => {
+            let (_, pt) = transpile_body_in(p, stream)?;
+            match m {
+                Some(m) if n == m => Ok((None, quote!(#pt.times(#n)))),
+                Some(m) => Ok((None, quote!(#pt.between(#n, #m)))),
+                None => Ok((None, quote!(#pt.at_least(#n)))),
             }
-            PCheck(p) => {
-                let (_, pt) = p.transpile_body()?;
-                Ok((None, quote!(check(#pt))))
+        }
+        PChain(b, p, o) => {
+            let (_, pt) = transpile_body_in(p, stream)?;
+            let (_, ot) = transpile_body_in(o, stream)?;
+            if *b {
+                Ok((
+                    None,
+                    quote!(celma_v0_core::parser::chain::chainl1(#pt, #ot)),
+                ))
+            } else {
+                Ok((
+                    None,
+                    quote!(celma_v0_core::parser::chain::chainr1(#pt, #ot)),
+                ))
             }
-            POptional(p) => {
-                let (_, pt) = p.transpile_body()?;
-                Ok((None, quote!(#pt.opt())))
+        }
+        PSeparated(b, p, s) => {
+            let (_, pt) = transpile_body_in(p, stream)?;
+            let (_, st) = transpile_body_in(s, stream)?;
+            if *b {
+                Ok((
+                    None,
+                    quote!(celma_v0_core::parser::repeat::sep_by(#pt, #st)),
+                ))
+            } else {
+                Ok((
+                    None,
+                    quote!(celma_v0_core::parser::repeat::sep_by1(#pt, #st)),
+                ))
             }
-            PRepeat(b, p) => {
-                let (_, pt) = p.transpile_body()?;
-                if *b {
-                    Ok((None, quote!(#pt.opt_rep())))
-                } else {
-                    Ok((None, quote!(#pt.rep())))
-                }
+        }
+        PLabel(p, l) => {
+            let (_, pt) = transpile_body_in(p, stream)?;
+            Ok((None, quote!(celma_v0_core::parser::label::label(#pt, #l))))
+        }
+        PCut(p) => {
+            let (pp, pt) = transpile_body_in(p, stream)?;
+            Ok((pp, quote!(celma_v0_core::parser::cut::cut(#pt))))
+        }
+    }
+}
+
+pub trait TranspileSkip<E> {
+    fn transpile_skip(&self) -> Result<E, Error>;
+}
+
+impl TranspileSkip<TokenStream> for ASTParsec {
+    fn transpile_skip(&self) -> Result<TokenStream, Error> {
+        transpile_skip_in(self, None)
+    }
+}
+
+fn transpile_skip_in(ast: &ASTParsec, stream: Option<&TokenStream>) -> Result<TokenStream, Error> {
+    match ast {
+        PSequence(l, r) => {
+            let lt = transpile_skip_in(l, stream)?;
+            let rt = transpile_skip_in(r, stream)?;
+            Ok(quote!(#lt.and_right(#rt)))
+        }
+        PTry(p) => {
+            let pt = transpile_skip_in(p, stream)?;
+            Ok(quote!(a_try(#pt)))
+        }
+        POptional(p) => {
+            let pt = transpile_skip_in(p, stream)?;
+            Ok(quote!(#pt.opt()))
+        }
+        PRepeat(b, p) => {
+            let pt = transpile_skip_in(p, stream)?;
+            if *b {
+                Ok(quote!(celma_v0_core::parser::repeat::skip_many(#pt)))
+            } else {
+                Ok(quote!(celma_v0_core::parser::repeat::skip_many1(#pt)))
             }
         }
+        PLabel(p, l) => {
+            let pt = transpile_skip_in(p, stream)?;
+            Ok(quote!(celma_v0_core::parser::label::label(#pt, #l)))
+        }
+        PCut(p) => {
+            let pt = transpile_skip_in(p, stream)?;
+            Ok(quote!(celma_v0_core::parser::cut::cut(#pt)))
+        }
+        _ => Ok(transpile_body_in(ast, stream)?.1),
     }
 }
@@ -14,6 +14,7 @@
    limitations under the License.
 */
 
+#![allow(clippy::bool_assert_comparison)]
 #![recursion_limit = "256"]
 
 pub mod parser;
@@ -28,9 +28,11 @@ mod tests_and {
         let response = celma_parsec_rules().parse(CharStream::new("let a:{char} = 'a'"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 vec!(ASTParsecRule {
+                    memo: false,
+                    left_recursive: false,
                     name: String::from("a"),
                     input: String::from("char"),
                     returns: String::from("char"),
@@ -48,16 +50,20 @@ mod tests_and {
         ));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 vec!(
                     ASTParsecRule {
+                        memo: false,
+                        left_recursive: false,
                         name: String::from("a"),
                         input: String::from("char"),
                         returns: String::from("char"),
                         rule: PCode(String::from("char(\'a\')")),
                     },
                     ASTParsecRule {
+                        memo: false,
+                        left_recursive: false,
                         name: String::from("b"),
                         input: String::from("char"),
                         returns: String::from("char"),
@@ -76,10 +82,12 @@ mod tests_and {
         ));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 vec!(
                     ASTParsecRule {
+                        memo: false,
+                        left_recursive: false,
                         name: String::from("a"),
                         input: String::from("char"),
                         returns: String::from("char"),
@@ -89,6 +97,8 @@ mod tests_and {
                         ),
                     },
                     ASTParsecRule {
+                        memo: false,
+                        left_recursive: false,
                         name: String::from("b"),
                         input: String::from("char"),
                         returns: String::from("char"),
@@ -116,8 +126,8 @@ mod tests_and {
         ));
 
         match response {
-            Success(_, _, _) => assert_eq!(true, true),
-            Reject(_, _) => assert_eq!(true, false),
+            Success(_, _, _, _) => assert_eq!(true, true),
+            Reject(_, _, _) => assert_eq!(true, false),
         };
     }
 
@@ -136,8 +146,73 @@ mod tests_and {
         ));
 
         match response {
-            Success(_, _, _) => assert_eq!(true, true),
-            Reject(_, _) => assert_eq!(true, false),
+            Success(_, _, _, _) => assert_eq!(true, true),
+            Reject(_, _, _) => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_parse_one_memo_rule() {
+        let response = celma_parsec_rules().parse(CharStream::new("@memo let a:{char} = 'a'"));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(
+                ast,
+                vec!(ASTParsecRule {
+                    memo: true,
+                    left_recursive: false,
+                    name: String::from("a"),
+                    input: String::from("char"),
+                    returns: String::from("char"),
+                    rule: PAtom('a'),
+                })
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_memo_rule_after_another_rule() {
+        let response = celma_parsec_rules().parse(CharStream::new("let a = 'a' @memo let b = 'b'"));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(
+                ast.iter().map(|r| r.memo).collect::<Vec<_>>(),
+                vec![false, true]
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_left_recursive_rule_after_a_labelled_rule() {
+        let response = celma_parsec_rules().parse(CharStream::new(
+            r#"let a = 'a' @ "letter" @left_rec let b = b 'b' | 'b'"#,
+        ));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(
+                ast.iter()
+                    .map(|r| (r.memo, r.left_recursive))
+                    .collect::<Vec<_>>(),
+                vec![(false, false), (true, true)]
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_parse_one_left_recursive_rule() {
+        let response = celma_parsec_rules().parse(CharStream::new("@left_rec let a = a 'a' | 'a'"));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(
+                ast.iter()
+                    .map(|r| (r.memo, r.left_recursive))
+                    .collect::<Vec<_>>(),
+                vec![(true, true)]
+            ),
+            _ => assert_eq!(true, false),
         };
     }
 }
@@ -17,9 +17,10 @@
 #[cfg(test)]
 mod tests_and {
     use celma_v0_ast::syntax::ASTParsec::{
-        PBind, PChoice, PCode, PEpsilon, PMap, POptional, PRepeat, PSequence,
+        PAtom, PAtoms, PBind, PBounded, PChain, PChoice, PClass, PCode, PCut, PEpsilon, PIdent,
+        PLabel, PLookahead, PMap, POptional, PRecognize, PRepeat, PSeparated, PSequence,
     };
-    use celma_v0_core::parser::response::Response::Success;
+    use celma_v0_core::parser::response::Response::{Reject, Success};
     use celma_v0_core::parser::specs::Parse;
     use celma_v0_core::stream::char_stream::CharStream;
     use celma_v0_parser::parser::celma_parsec;
@@ -29,7 +30,7 @@ mod tests_and {
         let response = celma_parsec().parse(CharStream::new("{char('a')}"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(ast, PCode(String::from("char(\'a\')"))),
+            Success(ast, _, _, _) => assert_eq!(ast, PCode(String::from("char(\'a\')"))),
             _ => panic!(),
         };
     }
@@ -39,7 +40,7 @@ mod tests_and {
         let response = celma_parsec().parse(CharStream::new("{char('a')} {char('b')}"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 PSequence(
                     PCode(String::from("char(\'a\')")).wrap(),
@@ -55,7 +56,7 @@ mod tests_and {
         let response = celma_parsec().parse(CharStream::new("{char('a')} | {char('b')}"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 PChoice(
                     PCode(String::from("char(\'a\')")).wrap(),
@@ -71,7 +72,7 @@ mod tests_and {
         let response = celma_parsec().parse(CharStream::new("c={char('a')}"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 PBind(String::from("c"), PCode(String::from("char(\'a\')")).wrap(),)
             ),
@@ -84,7 +85,7 @@ mod tests_and {
         let response = celma_parsec().parse(CharStream::new("c={char('a')}?"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 PBind(
                     String::from("c"),
@@ -100,7 +101,7 @@ mod tests_and {
         let response = celma_parsec().parse(CharStream::new("c={char('a')}*"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 PBind(
                     String::from("c"),
@@ -116,7 +117,7 @@ mod tests_and {
         let response = celma_parsec().parse(CharStream::new("c={char('a')}+"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 PBind(
                     String::from("c"),
@@ -132,7 +133,7 @@ mod tests_and {
         let response = celma_parsec().parse(CharStream::new("a={char('a')}+ b={char('b')}+"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 PSequence(
                     PBind(
@@ -156,7 +157,7 @@ mod tests_and {
         let response = celma_parsec().parse(CharStream::new("a={char('a')}+ | b={char('b')}+"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 PChoice(
                     PBind(
@@ -180,7 +181,7 @@ mod tests_and {
         let response = celma_parsec().parse(CharStream::new("a={char('a')} -> { Result(a) }"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 PMap(
                     PBind(String::from("a"), PCode(String::from("char(\'a\')")).wrap(),).wrap(),
@@ -198,7 +199,7 @@ mod tests_and {
         ));
 
         match response {
-            Success(ast, _, _) => assert_eq!(
+            Success(ast, _, _, _) => assert_eq!(
                 ast,
                 PMap(
                     PBind(
@@ -222,7 +223,7 @@ mod tests_and {
         let response = celma_parsec().parse(CharStream::new("()"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(ast, PEpsilon(),),
+            Success(ast, _, _, _) => assert_eq!(ast, PEpsilon(),),
             _ => panic!(),
         };
     }
@@ -232,7 +233,209 @@ mod tests_and {
         let response = celma_parsec().parse(CharStream::new("a=()"));
 
         match response {
-            Success(ast, _, _) => assert_eq!(ast, PBind(String::from("a"), PEpsilon().wrap()),),
+            Success(ast, _, _, _) => assert_eq!(ast, PBind(String::from("a"), PEpsilon().wrap()),),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_labelled_repeatable_character_with_binding() {
+        let response = celma_parsec().parse(CharStream::new("a={char('a')}+ @ \"letters\""));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(
+                ast,
+                PBind(
+                    String::from("a"),
+                    PLabel(
+                        PRepeat(false, PCode(String::from("char(\'a\')")).wrap()).wrap(),
+                        String::from("letters")
+                    )
+                    .wrap(),
+                )
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_cut_sequence_with_binding() {
+        let response = celma_parsec().parse(CharStream::new("a => b='b'"));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(
+                ast,
+                PSequence(
+                    PIdent(String::from("a")).wrap(),
+                    PCut(PBind(String::from("b"), PAtom('b').wrap()).wrap()).wrap()
+                )
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_separated_repeatable_ident() {
+        let response = celma_parsec().parse(CharStream::new("a+ % ','"));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(
+                ast,
+                PSeparated(false, PIdent(String::from("a")).wrap(), PAtom(',').wrap())
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_bounded_character() {
+        let response = celma_parsec().parse(CharStream::new("'a'{2,3}"));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(ast, PBounded(2, Some(3), PAtom('a').wrap())),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_an_exact_bounded_character() {
+        let response = celma_parsec().parse(CharStream::new("'a'{ 4 }"));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(ast, PBounded(4, Some(4), PAtom('a').wrap())),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_an_unbounded_character() {
+        let response = celma_parsec().parse(CharStream::new("'a'{2,}"));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(ast, PBounded(2, None, PAtom('a').wrap())),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_rejects_unordered_bounds() {
+        let response = celma_parsec().parse(CharStream::new("'a'{3,2}"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e.to_string(),
+                "expected ordered bounds, found end of stream"
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_rejects_an_overflowing_bound() {
+        let response = celma_parsec().parse(CharStream::new("'a'{99999999999999999999}"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e.to_string(),
+                "expected bounds within usize, found end of stream"
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_rejects_bounds_on_a_separated_repetition() {
+        let response = celma_parsec().parse(CharStream::new("a{2,3} % ','"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e.to_string(),
+                "expected unbounded separated repetition, found end of stream"
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_left_chain() {
+        let response = celma_parsec().parse(CharStream::new("a %< op"));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(
+                ast,
+                PChain(
+                    true,
+                    PIdent(String::from("a")).wrap(),
+                    PIdent(String::from("op")).wrap()
+                )
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_followed_by_ident() {
+        let response = celma_parsec().parse(CharStream::new("&a"));
+
+        match response {
+            Success(ast, _, _, _) => {
+                assert_eq!(ast, PLookahead(true, PIdent(String::from("a")).wrap()))
+            }
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_not_followed_by_keyword() {
+        let response = celma_parsec().parse(CharStream::new("\"let\" ~a"));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(
+                ast,
+                PSequence(
+                    PAtoms(vec!['l', 'e', 't']).wrap(),
+                    PLookahead(false, PIdent(String::from("a")).wrap()).wrap()
+                )
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_character_class() {
+        let response = celma_parsec().parse(CharStream::new("[: xid_start :]"));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(ast, PClass(String::from("xid_start"))),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_rust_identifier() {
+        let response =
+            celma_parsec().parse(CharStream::new("([:xid_start:]|'_') [:xid_continue:]*"));
+
+        match response {
+            Success(ast, _, _, _) => assert_eq!(
+                ast,
+                PSequence(
+                    PChoice(PClass(String::from("xid_start")).wrap(), PAtom('_').wrap()).wrap(),
+                    PRepeat(true, PClass(String::from("xid_continue")).wrap()).wrap()
+                )
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn it_parse_a_recognized_ident() {
+        let response = celma_parsec().parse(CharStream::new("$a"));
+
+        match response {
+            Success(ast, _, _, _) => {
+                assert_eq!(ast, PRecognize(PIdent(String::from("a")).wrap()))
+            }
             _ => panic!(),
         };
     }
@@ -25,6 +25,77 @@ mod tests_and {
     use celma_v0_parser::parser::celma_parsec_rules;
     use celma_v0_parser::transpiler::Transpile;
 
+    #[test]
+    fn it_bounds_recognizing_rules_with_slices() {
+        let response = celma_parsec_rules()
+            .parse(CharStream::new(
+                "let a:{&'a str} = $b let b:{Vec<char>} = 'b'+",
+            ))
+            .map(|ast| ast.transpile());
+
+        match response {
+            Success(ast, _, _, _) => assert!(
+                ast.unwrap().to_string().contains(
+                    &quote!(S: celma_v0_core::stream::specs::Slice<Item=char, Slice=&'a str>,)
+                        .to_string()
+                )
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_bounds_other_rules_with_streams() {
+        let response = celma_parsec_rules()
+            .parse(CharStream::new(
+                "let a:{&'a str} = $b let b:{Vec<char>} = 'b'+",
+            ))
+            .map(|ast| ast.transpile());
+
+        match response {
+            Success(ast, _, _, _) => assert!(ast.unwrap().to_string().contains(
+                &quote!(S: celma_v0_core::stream::specs::Stream<Item=char>,).to_string()
+            )),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_recognizes_slices_of_the_rule_stream() {
+        let response = celma_parsec_rules()
+            .parse(CharStream::new("let a:{&'a str} = $'a'"))
+            .map(|ast| ast.transpile());
+
+        match response {
+            Success(ast, _, _, _) => assert!(
+                ast.unwrap().to_string().contains(
+                    &quote!(celma_v0_core::parser::recognize::recognize::<_, _, S>(
+                        celma_v0_core::parser::char::a_char('a')
+                    ))
+                    .to_string()
+                )
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_bounds_recognizing_rules_with_array_slices() {
+        let response = celma_parsec_rules()
+            .parse(CharStream::new("let a{u8}:{&'a [u8]} = $(b b)"))
+            .map(|ast| ast.transpile());
+
+        match response {
+            Success(ast, _, _, _) => assert!(
+                ast.unwrap().to_string().contains(
+                    &quote!(S: celma_v0_core::stream::specs::Slice<Item=u8, Slice=&'a [u8]>,)
+                        .to_string()
+                )
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
     #[test]
     fn it_parse_two_char_rules() {
         let response = celma_parsec_rules()
@@ -34,7 +105,25 @@ mod tests_and {
             .map(|ast| ast.transpile());
 
         match response {
-            Success(ast, _, _) => assert_eq!(ast.unwrap().to_string(), expect_code().to_string()),
+            Success(ast, _, _, _) => {
+                assert_eq!(ast.unwrap().to_string(), expect_code().to_string())
+            }
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_parse_two_rules_with_different_inputs() {
+        let response = celma_parsec_rules()
+            .parse(CharStream::new(
+                "let a:{char} = 'a' let b{u8}:{u8} = {any()}",
+            ))
+            .map(|ast| ast.transpile());
+
+        match response {
+            Success(ast, _, _, _) => {
+                assert_eq!(ast.unwrap().to_string().contains("__celma_rules"), false)
+            }
             _ => assert_eq!(true, false),
         };
     }
@@ -42,9 +131,11 @@ mod tests_and {
     #[rustfmt::skip]
     fn expect_code() -> TokenStream {
         quote!(
-            pub fn a<'a, S: 'a>() -> impl celma_v0_core::parser::specs::Parse<Vec<char>, S>
-                   + celma_v0_core::parser::specs::Combine<Vec<char> >
-                   + 'a
+            #[allow(non_snake_case)]
+            fn __celma_rules_a<'a, S: 'a>() -> (
+                celma_v0_core::parser::recursive::Recursive<'a, Vec<char>, S>,
+                celma_v0_core::parser::recursive::Recursive<'a, Vec<char>, S>,
+            )
             where
                 S: celma_v0_core::stream::specs::Stream<Item = char>,
             {
@@ -59,27 +150,31 @@ mod tests_and {
                 use celma_v0_core::parser::repeat::RepeatOperation;
                 use celma_v0_core::parser::specs::Parse;
 
-                celma_v0_core::parser::core::parser(celma_v0_core::parser::lazy::lazy(|| b()))
+                let rule_a = celma_v0_core::parser::recursive::Recursive::new();
+                let rule_b = celma_v0_core::parser::recursive::Recursive::new();
+                rule_a.define(rule_b.reference());
+                rule_b.define(celma_v0_core::parser::char::a_char('b').rep());
+                (rule_a, rule_b,)
             }
 
-            pub fn b<'a, S: 'a>() -> impl celma_v0_core::parser::specs::Parse<Vec<char>, S>
+            pub fn a<'a, S: 'a>() -> impl celma_v0_core::parser::specs::Parse<Vec<char>, S>
                    + celma_v0_core::parser::specs::Combine<Vec<char> >
                    + 'a
             where
                 S: celma_v0_core::stream::specs::Stream<Item = char>,
             {
-                use celma_v0_core::parser::a_try::a_try;
-                use celma_v0_core::parser::and::AndOperation;
-                use celma_v0_core::parser::check::check;
-                use celma_v0_core::parser::lookahead::lookahead;
-                use celma_v0_core::parser::map::MapOperation;
-                use celma_v0_core::parser::not::NotOperation;
-                use celma_v0_core::parser::option::OptionalOperation;
-                use celma_v0_core::parser::or::OrOperation;
-                use celma_v0_core::parser::repeat::RepeatOperation;
-                use celma_v0_core::parser::specs::Parse;
+                let (rule_a, rule_b,) = __celma_rules_a();
+                rule_a.hold(rule_b)
+            }
 
-                celma_v0_core::parser::core::parser(celma_v0_core::parser::char::a_char('b').rep())
+            pub fn b<'a, S: 'a>() -> impl celma_v0_core::parser::specs::Parse<Vec<char>, S>
+                   + celma_v0_core::parser::specs::Combine<Vec<char> >
+                   + 'a
+            where
+                S: celma_v0_core::stream::specs::Stream<Item = char>,
+            {
+                let (rule_a, rule_b,) = __celma_rules_a();
+                rule_b.hold(rule_a)
             }
         )
     }
@@ -30,7 +30,7 @@ mod tests_and {
             .map(|ast| ast.transpile_body());
 
         match response {
-            Success(Ok((_, ast)), _, _) => assert_eq!(
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
                 ast.to_string(),
                 quote!(celma_v0_core::parser::char::a_char('a')).to_string()
             ),
@@ -45,7 +45,7 @@ mod tests_and {
             .map(|ast| ast.transpile_body());
 
         match response {
-            Success(Ok((_, ast)), _, _) => assert_eq!(
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
                 ast.to_string(),
                 quote!(
                     celma_v0_core::parser::char::a_char('a')
@@ -64,7 +64,7 @@ mod tests_and {
             .map(|ast| ast.transpile_body());
 
         match response {
-            Success(Ok((_, ast)), _, _) => assert_eq!(
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
                 ast.to_string(),
                 quote!(
                     celma_v0_core::parser::char::a_char('a')
@@ -83,7 +83,7 @@ mod tests_and {
             .map(|ast| ast.transpile_body());
 
         match response {
-            Success(Ok((params, ast)), _, _) => {
+            Success(Ok((params, ast)), _, _, _) => {
                 assert_eq!(
                     ast.to_string(),
                     quote!(
@@ -105,7 +105,7 @@ mod tests_and {
             .map(|ast| ast.transpile_body());
 
         match response {
-            Success(Ok((params, _)), _, _) => assert_eq!(params, Some(String::from("(a,b)"))),
+            Success(Ok((params, _)), _, _, _) => assert_eq!(params, Some(String::from("(a,b)"))),
             _ => assert_eq!(true, false),
         };
     }
@@ -117,7 +117,7 @@ mod tests_and {
             .map(|ast| ast.transpile_body());
 
         match response {
-            Success(Ok((params, ast)), _, _) => {
+            Success(Ok((params, ast)), _, _, _) => {
                 assert_eq!(
                     ast.to_string(),
                     quote!(
@@ -141,7 +141,7 @@ mod tests_and {
             .map(|ast| ast.transpile_body());
 
         match response {
-            Success(Ok((params, ast)), _, _) => {
+            Success(Ok((params, ast)), _, _, _) => {
                 assert_eq!(
                     ast.to_string(),
                     quote!(
@@ -165,7 +165,7 @@ mod tests_and {
             .map(|ast| ast.transpile_body());
 
         match response {
-            Success(Ok((_, ast)), _, _) => assert_eq!(
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
                 ast.to_string(),
                 quote!(
                     celma_v0_core::parser::char::a_char('a')
@@ -176,4 +176,214 @@ mod tests_and {
             _ => assert_eq!(true, false),
         };
     }
+
+    #[test]
+    fn it_transpile_one_labelled_character() {
+        let response = celma_parsec()
+            .parse(CharStream::new("'a' @ \"letter\""))
+            .map(|ast| ast.transpile_body());
+
+        match response {
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
+                ast.to_string(),
+                quote!(celma_v0_core::parser::label::label(
+                    celma_v0_core::parser::char::a_char('a'),
+                    "letter"
+                ))
+                .to_string()
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_transpile_one_cut_sequence() {
+        let response = celma_parsec()
+            .parse(CharStream::new("'a' => b='b'"))
+            .map(|ast| ast.transpile_body());
+
+        match response {
+            Success(Ok((Some(b), ast)), _, _, _) => {
+                assert_eq!(b, "b");
+                assert_eq!(
+                    ast.to_string(),
+                    quote!(celma_v0_core::parser::char::a_char('a').and_right(
+                        celma_v0_core::parser::cut::cut(celma_v0_core::parser::char::a_char('b'))
+                    ))
+                    .to_string()
+                )
+            }
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_transpile_one_separated_character() {
+        let response = celma_parsec()
+            .parse(CharStream::new("'a' % ','"))
+            .map(|ast| ast.transpile_body());
+
+        match response {
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
+                ast.to_string(),
+                quote!(celma_v0_core::parser::repeat::sep_by(
+                    celma_v0_core::parser::char::a_char('a'),
+                    celma_v0_core::parser::char::a_char(',')
+                ))
+                .to_string()
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_transpile_one_bounded_character() {
+        let response = celma_parsec()
+            .parse(CharStream::new("'a'{2,3}"))
+            .map(|ast| ast.transpile_body());
+
+        match response {
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
+                ast.to_string(),
+                quote!(celma_v0_core::parser::char::a_char('a').between(2usize, 3usize))
+                    .to_string()
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_transpile_one_right_chain() {
+        let response = celma_parsec()
+            .parse(CharStream::new("'a' %> {op}"))
+            .map(|ast| ast.transpile_body());
+
+        match response {
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
+                ast.to_string(),
+                quote!(celma_v0_core::parser::chain::chainr1(
+                    celma_v0_core::parser::char::a_char('a'),
+                    op
+                ))
+                .to_string()
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_transpile_one_skipped_repetition() {
+        let response = celma_parsec()
+            .parse(CharStream::new("' '* v='a'"))
+            .map(|ast| ast.transpile_body());
+
+        match response {
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
+                ast.to_string(),
+                quote!(
+                    celma_v0_core::parser::repeat::skip_many(celma_v0_core::parser::char::a_char(
+                        ' '
+                    ))
+                    .and_right(celma_v0_core::parser::char::a_char('a'))
+                )
+                .to_string()
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_transpile_one_not_followed_by_character() {
+        let response = celma_parsec()
+            .parse(CharStream::new("~'a'"))
+            .map(|ast| ast.transpile_body());
+
+        match response {
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
+                ast.to_string(),
+                quote!(celma_v0_core::parser::lookahead::not_followed_by(
+                    celma_v0_core::parser::char::a_char('a')
+                ))
+                .to_string()
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_transpile_one_property_class() {
+        let response = celma_parsec()
+            .parse(CharStream::new("[:xid_start:]"))
+            .map(|ast| ast.transpile_body());
+
+        match response {
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
+                ast.to_string(),
+                quote!(celma_v0_core::parser::char::xid_start()).to_string()
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_transpile_one_category_class() {
+        let response = celma_parsec()
+            .parse(CharStream::new("[:Lu:]"))
+            .map(|ast| ast.transpile_body());
+
+        match response {
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
+                ast.to_string(),
+                quote!(celma_v0_core::parser::char::category("Lu")).to_string()
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_transpile_one_recognized_character() {
+        let response = celma_parsec()
+            .parse(CharStream::new("$'a'"))
+            .map(|ast| ast.transpile_body());
+
+        match response {
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
+                ast.to_string(),
+                quote!(celma_v0_core::parser::recognize::recognize(
+                    celma_v0_core::parser::char::a_char('a')
+                ))
+                .to_string()
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_transpile_one_major_category_class() {
+        let response = celma_parsec()
+            .parse(CharStream::new("[:N:]"))
+            .map(|ast| ast.transpile_body());
+
+        match response {
+            Success(Ok((_, ast)), _, _, _) => assert_eq!(
+                ast.to_string(),
+                quote!(celma_v0_core::parser::char::category("N")).to_string()
+            ),
+            _ => assert_eq!(true, false),
+        };
+    }
+
+    #[test]
+    fn it_rejects_an_unknown_class() {
+        let response = celma_parsec()
+            .parse(CharStream::new("[:greek:]"))
+            .map(|ast| ast.transpile_body());
+
+        match response {
+            Success(Err(e), _, _, _) => {
+                assert_eq!(e.to_string(), "unknown character class [:greek:]")
+            }
+            _ => assert_eq!(true, false),
+        };
+    }
 }
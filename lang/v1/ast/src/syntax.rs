@@ -37,6 +37,7 @@ pub enum ASTParsec<I> {
     PIdent(String),
     PAtom(I),
     PAtoms(Vec<I>),
+    PClass(String),
     PBind(String, Box<ASTParsec<I>>),
     PMap(Box<ASTParsec<I>>, String),
     PSequence(Box<ASTParsec<I>>, Box<ASTParsec<I>>),
@@ -44,7 +45,14 @@ pub enum ASTParsec<I> {
     PNot(Box<ASTParsec<I>>),
     PTry(Box<ASTParsec<I>>),
     PCheck(Box<ASTParsec<I>>),
+    PRecognize(Box<ASTParsec<I>>),
+    PLookahead(bool, Box<ASTParsec<I>>),
     PRepeat(Box<ASTParsec<I>>),
+    PBounded(usize, Option<usize>, Box<ASTParsec<I>>),
+    PSeparated(Box<ASTParsec<I>>, Box<ASTParsec<I>>),
+    PChain(bool, Box<ASTParsec<I>>, Box<ASTParsec<I>>),
+    PLabel(Box<ASTParsec<I>>, String),
+    PCut(Box<ASTParsec<I>>),
 }
 
 impl<I> ASTParsec<I> {
@@ -14,16 +14,22 @@
    limitations under the License.
 */
 
-use celma_v0_core::parser::core::{eos, returns};
+use celma_v0_core::parser::bind::BindOperation;
+use celma_v0_core::parser::core::{eos, fail, parser, returns};
+use celma_v0_core::parser::cut::CutOperation;
+use celma_v0_core::parser::label::LabelOperation;
 use celma_v0_core::parser::literal::{delimited_char, delimited_string};
+use celma_v0_core::parser::specs::{Combine, Parse};
+use celma_v0_core::stream::specs::Stream;
 
 use celma_v0_macro::parsec_rules;
 use celma_v1_ast::syntax::ASTParsec::{
-    PAtom, PAtoms, PBind, PCheck, PChoice, PEpsilon, PIdent, PMap, PNot, PRepeat, PSequence, PTry,
+    PAtom, PAtoms, PBind, PBounded, PChain, PCheck, PChoice, PClass, PCut, PEpsilon, PIdent,
+    PLabel, PLookahead, PMap, PNot, PRecognize, PRepeat, PSeparated, PSequence, PTry,
 };
 use celma_v1_ast::syntax::{ASTParsec, ASTParsecRule};
 
-use celma_v0_core::parser::char::{alpha, digit};
+use celma_v0_core::parser::char::{alpha, digit, not_char};
 use celma_v1_ast::syntax::ASTType::{PChar, POther, PUnit};
 
 fn mk_rule(
@@ -42,34 +48,65 @@ fn mk_rule(
     }
 }
 
+type Bounds = (usize, Option<usize>);
+
+#[allow(clippy::too_many_arguments)]
 fn mk_ast_parsec(
     bind: Option<String>,
     atom: ASTParsec<char>,
     occ: Option<char>,
-    add: Option<(bool, ASTParsec<char>)>,
+    bounds: Option<Bounds>,
+    sep: Option<(Option<char>, ASTParsec<char>)>,
+    label: Option<String>,
+    add: Option<(Option<char>, ASTParsec<char>)>,
     trans: Option<String>,
 ) -> ASTParsec<char> {
-    let occ = match occ {
-        Some('?') => PChoice(atom.wrap(), PEpsilon().wrap()),
-        Some('*') => PChoice(PRepeat(atom.wrap()).wrap(), PEpsilon().wrap()),
-        Some('+') => PRepeat(atom.wrap()),
+    let atom = match bounds {
+        Some((n, m)) => PBounded(n, m, atom.wrap()),
+        None => atom,
+    };
+
+    let (sep, chain) = match sep {
+        Some((None, sep)) => (Some(sep), None),
+        Some((Some(c), op)) => (None, Some((c == '<', op))),
+        None => (None, None),
+    };
+
+    let occ = match (occ, sep) {
+        (Some('+'), Some(sep)) => PSeparated(atom.wrap(), sep.wrap()),
+        (_, Some(sep)) => PChoice(
+            PSeparated(atom.wrap(), sep.wrap()).wrap(),
+            PEpsilon().wrap(),
+        ),
+        (Some('?'), None) => PChoice(atom.wrap(), PEpsilon().wrap()),
+        (Some('*'), None) => PChoice(PRepeat(atom.wrap()).wrap(), PEpsilon().wrap()),
+        (Some('+'), None) => PRepeat(atom.wrap()),
         _ => atom,
     };
 
+    let occ = if let Some((left, op)) = chain {
+        PChain(left, occ.wrap(), op.wrap())
+    } else {
+        occ
+    };
+
+    let occ = if let Some(value) = label {
+        PLabel(occ.wrap(), value)
+    } else {
+        occ
+    };
+
     let bind = if let Some(value) = bind {
         PBind(value, occ.wrap())
     } else {
         occ
     };
 
-    let add = if let Some((choice, parser)) = add {
-        if choice {
-            PChoice(bind.wrap(), parser.wrap())
-        } else {
-            PSequence(bind.wrap(), parser.wrap())
-        }
-    } else {
-        bind
+    let add = match add {
+        Some((Some('|'), parser)) => PChoice(bind.wrap(), parser.wrap()),
+        Some((Some('='), parser)) => PSequence(bind.wrap(), PCut(parser.wrap()).wrap()),
+        Some((_, parser)) => PSequence(bind.wrap(), parser.wrap()),
+        None => bind,
     };
 
     if let Some(value) = trans {
@@ -79,11 +116,40 @@ fn mk_ast_parsec(
     }
 }
 
+fn mk_number(digits: Vec<char>) -> Option<usize> {
+    digits.into_iter().collect::<String>().parse().ok()
+}
+
+// Bounds are None when a number does not fit in usize.
+fn mk_bounds(n: Option<usize>, m: Option<Option<Option<usize>>>) -> Option<Bounds> {
+    match (n, m) {
+        (Some(n), None) => Some((n, Some(n))),
+        (Some(n), Some(None)) => Some((n, None)),
+        (Some(n), Some(Some(Some(m)))) => Some((n, Some(m))),
+        _ => None,
+    }
+}
+
+fn checked_bounds<'a, P, S>(p: P) -> impl Parse<Bounds, S> + Combine<Bounds> + 'a
+where
+    P: Parse<Option<Bounds>, S> + Combine<Option<Bounds>> + 'a,
+    S: Stream + 'a,
+{
+    p.bind(|bounds| match bounds {
+        Some((n, Some(m))) if n > m => parser(fail(false).label("ordered bounds").cut()),
+        Some((n, m)) => parser(returns((n, m))),
+        None => parser(fail(false).label("bounds within usize").cut()),
+    })
+}
+
 fn mk_atom(operation: Option<char>, parsec: ASTParsec<char>) -> ASTParsec<char> {
     match operation {
         Some('^') => PNot(parsec.wrap()),
         Some('!') => PTry(parsec.wrap()),
         Some('#') => PCheck(parsec.wrap()),
+        Some('$') => PRecognize(parsec.wrap()),
+        Some('&') => PLookahead(true, parsec.wrap()),
+        Some('~') => PLookahead(false, parsec.wrap()),
         _ => parsec,
     }
 }
@@ -109,17 +175,27 @@ parsec_rules!(
     ) -> { mk_rule(p.is_some(), n, i, r, b) }
 
     let parsec:{ASTParsec<char>} = (
-        skip b=!(binding)? a=atom o=('?'|'*'|'+')? d=additional? t=transform? skip
-    ) -> { mk_ast_parsec(b, a, o, d, t) }
-
-    let binding:{String} = skip _=ident '=' skip
-    let additional:{(bool,ASTParsec<char>)} = (skip c='|'? skip p=parsec) -> { (c.is_some(), p) }
+        skip b=!(binding)? a=atom o=('?'|'*'|'+')? n=bounds? s=separator? l=label? d=additional? t=transform? skip
+    ) -> { mk_ast_parsec(b, a, o, n, s, l, d, t) }
+
+    let binding:{String} = skip _=ident '=' {lookahead(not_char('>'))} skip
+    let bounds:{Bounds} = {checked_bounds(try_bounds())}
+    let try_bounds:{Option<Bounds>} = !(raw_bounds)
+    let raw_bounds:{Option<Bounds>} = (
+        skip '{' skip n=number skip m=(',' skip _=number? skip)? '}'
+    ) -> { mk_bounds(n, m) }
+    let number:{Option<usize>} = n=#(digit+) -> { mk_number(n) }
+    let separator:{(Option<char>,ASTParsec<char>)} = (skip '%' c=('<'|'>')? skip p=atom) -> { (c, p) }
+    let label:{String} = (skip '@' skip _=delimited_string)
+    let additional:{(Option<char>,ASTParsec<char>)} = (skip c=('|'|cut)? skip p=parsec) -> { (c, p) }
+    let cut:{char} = "=>" -> { '=' }
 
     let atom:{ASTParsec<char>} = (
-        skip o=('^'|'!'|'#')? skip p=(atom_block|atom_ident|atom_char|atom_string) skip
+        skip o=('^'|'!'|'#'|'$'|'&'|'~')? skip p=(atom_block|atom_class|atom_ident|atom_char|atom_string) skip
     ) -> { mk_atom(o, p) }
 
     let atom_block:{ASTParsec<char>} = ('(' p=parsec? ')') -> { p.unwrap_or_else(PEpsilon) }
+    let atom_class:{ASTParsec<char>} = ('[' skip ':' c=ident ':' skip ']') -> { PClass(c) }
     let atom_ident:{ASTParsec<char>} = c=ident -> { PIdent(c) }
     let atom_char:{ASTParsec<char>} = c=delimited_char -> { PAtom(c) }
     let atom_string:{ASTParsec<char>} = c=delimited_string -> { PAtoms(c.chars().collect()) }
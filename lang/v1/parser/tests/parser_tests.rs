@@ -14,8 +14,12 @@
  * limitations under the License.
  */
 
+#![allow(clippy::bool_assert_comparison)]
+
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod parser_tests {
+    use celma_v0_core::parser::response::Response::Reject;
     use celma_v0_core::parser::specs::Parse;
     use celma_v0_core::stream::char_stream::CharStream;
     use celma_v0_core::stream::specs::Len;
@@ -23,7 +27,8 @@ mod parser_tests {
         atom_char, atom_ident, atom_string, code, code_content, kind, parsec, rule,
     };
     use celma_v1_ast::syntax::ASTParsec::{
-        PAtom, PAtoms, PBind, PCheck, PChoice, PEpsilon, PIdent, PNot, PRepeat, PSequence, PTry,
+        PAtom, PAtoms, PBind, PBounded, PChain, PCheck, PChoice, PClass, PCut, PEpsilon, PIdent,
+        PLabel, PLookahead, PNot, PRecognize, PRepeat, PSeparated, PSequence, PTry,
     };
     use celma_v1_ast::syntax::ASTParsecRule;
     use celma_v1_ast::syntax::ASTType::{PChar, PUnit};
@@ -90,10 +95,7 @@ mod parser_tests {
     fn should_parse_ident_body_code() {
         let response = code().parse(CharStream::new("{ titi }"));
 
-        assert_eq!(
-            response.fold(|v, _, _| v == String::from(" titi "), |_, _| false),
-            true
-        );
+        assert_eq!(response.fold(|v, _, _| v == " titi ", |_, _| false), true);
     }
 
     #[test]
@@ -101,10 +103,7 @@ mod parser_tests {
         let response = code().parse(CharStream::new("{ {titi} }"));
 
         assert_eq!(
-            response.fold(
-                |v, s, _| v == String::from(" {titi} ") && s.is_empty(),
-                |_, _| false
-            ),
+            response.fold(|v, s, _| v == " {titi} " && s.is_empty(), |_, _| false),
             true
         );
     }
@@ -263,6 +262,115 @@ mod parser_tests {
         );
     }
 
+    #[test]
+    fn should_parse_bind_labelled_ident_body() {
+        let response = parsec().parse(CharStream::new("a=entry @ \"value\""));
+
+        assert_eq!(
+            response.fold(
+                |v, _, _| v
+                    == PBind(
+                        String::from("a"),
+                        PLabel(PIdent(String::from("entry")).wrap(), String::from("value")).wrap()
+                    ),
+                |_, _| false
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn should_parse_cut_sequence() {
+        let response = parsec().parse(CharStream::new("a => b=entry"));
+
+        assert_eq!(
+            response.fold(
+                |v, _, _| v
+                    == PSequence(
+                        PIdent(String::from("a")).wrap(),
+                        PCut(PBind(String::from("b"), PIdent(String::from("entry")).wrap()).wrap())
+                            .wrap()
+                    ),
+                |_, _| false
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn should_parse_separated_ident() {
+        let response = parsec().parse(CharStream::new("entry % ','"));
+
+        assert_eq!(
+            response.fold(
+                |v, _, _| v
+                    == PChoice(
+                        PSeparated(PIdent(String::from("entry")).wrap(), PAtom(',').wrap()).wrap(),
+                        PEpsilon().wrap()
+                    ),
+                |_, _| false
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn should_parse_bounded_ident() {
+        let response = parsec().parse(CharStream::new("entry{2,}"));
+
+        assert_eq!(
+            response.fold(
+                |v, _, _| v == PBounded(2, None, PIdent(String::from("entry")).wrap()),
+                |_, _| false
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn should_reject_unordered_bounds() {
+        let response = parsec().parse(CharStream::new("'a'{3,2}"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e.to_string(),
+                "expected ordered bounds, found end of stream"
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn should_reject_an_overflowing_bound() {
+        let response = parsec().parse(CharStream::new("'a'{99999999999999999999}"));
+
+        match response {
+            Reject(_, _, e) => assert_eq!(
+                e.to_string(),
+                "expected bounds within usize, found end of stream"
+            ),
+            _ => panic!(),
+        };
+    }
+
+    #[test]
+    fn should_parse_right_chain() {
+        let response = parsec().parse(CharStream::new("entry %> op"));
+
+        assert_eq!(
+            response.fold(
+                |v, _, _| v
+                    == PChain(
+                        false,
+                        PIdent(String::from("entry")).wrap(),
+                        PIdent(String::from("op")).wrap()
+                    ),
+                |_, _| false
+            ),
+            true
+        );
+    }
+
     #[test]
     fn should_parse_epsilon() {
         let response = parsec().parse(CharStream::new("()"));
@@ -309,4 +417,40 @@ mod parser_tests {
             true
         );
     }
+
+    #[test]
+    fn should_parse_not_followed_by_ident() {
+        let response = parsec().parse(CharStream::new("~entry"));
+
+        assert_eq!(
+            response.fold(
+                |v, _, _| v == PLookahead(false, PIdent(String::from("entry")).wrap()),
+                |_, _| false
+            ),
+            true
+        );
+    }
+
+    #[test]
+    fn should_parse_character_class() {
+        let response = parsec().parse(CharStream::new("[:Lu:]"));
+
+        assert_eq!(
+            response.fold(|v, _, _| v == PClass(String::from("Lu")), |_, _| false),
+            true
+        );
+    }
+
+    #[test]
+    fn should_parse_recognized_ident() {
+        let response = parsec().parse(CharStream::new("$entry"));
+
+        assert_eq!(
+            response.fold(
+                |v, _, _| v == PRecognize(PIdent(String::from("entry")).wrap()),
+                |_, _| false
+            ),
+            true
+        );
+    }
 }